@@ -4,10 +4,11 @@ use std::io::Write;
 use numtoa::NumToA;
 
 use crate::model2d::Model2D;
-use crate::types::{AxisExportType, Axis};
+use crate::types::fill_value::export_fill_values;
+use crate::types::{AxisExportType, Axis, FillValue};
 use crate::types::generation_params::Params2D;
 
-impl Model2D {
+impl<T: FillValue> Model2D<T> {
     pub fn export_model(&self, name: &str, save: &[&str], axes_export: &[AxisExportType]) -> Result<(), std::io::Error> {
         let default_ax_type = vec![AxisExportType::AsSelf, AxisExportType::AsSelf];
 
@@ -22,7 +23,7 @@ impl Model2D {
         result += "{\"params2D\":";
 
         if save.contains(&"params") {
-            unimplemented!("Params 2D not implemented yet");
+            export_params(&mut result, &self.params);
         } else { result += "null" }
 
         result += ",\"output_axes\":";
@@ -44,7 +45,7 @@ impl Model2D {
 
         result += ",\"fill_values\":";
         if save.contains(&"fill_values") {
-            unimplemented!("Fill values for Model2D not implemented yet");
+            export_fill_values(&mut result, &self.layers_filling_values)
         } else { result += "null" }
 
         result += ",\"model\":";
@@ -90,19 +91,18 @@ fn export_border_num(result: &mut String, borders: &[Vec<i32>]){
     *result += "]";
 }
 
-fn export_model_num(result: &mut String, model: &[Vec<i32>]) {
-    let mut buf = [0u8; 12];
+pub(crate) fn export_model_num<T: FillValue>(result: &mut String, model: &[Vec<T>]) {
     *result += "[";
 
     for (x_num, x_ax) in model.iter().enumerate() {
         *result += "{\"x";
         *result += format!("{x_num}\":[").as_str();
 
-        result.push_str(x_ax[0].numtoa_str(10, &mut buf));
+        x_ax[0].export_value(result);
 
         for depth in x_ax[1..].iter() {
             result.push(',');
-            result.push_str(depth.numtoa_str(10, &mut buf));
+            depth.export_value(result);
         }
 
         if x_num != model.len() - 1 {
@@ -114,7 +114,7 @@ fn export_model_num(result: &mut String, model: &[Vec<i32>]) {
     *result += "]";
 }
 
-fn export_mask_num(result: &mut String, model_mask: &[Vec<u8>]) {
+pub(crate) fn export_mask_num(result: &mut String, model_mask: &[Vec<u8>]) {
     let mut buf = [0u8; 12];
     *result += "[";
 
@@ -138,7 +138,11 @@ fn export_mask_num(result: &mut String, model_mask: &[Vec<u8>]) {
     *result += "]";
 }
 
-fn export_true_axes(result: &mut String, params: &Params2D, axes_export: &[AxisExportType], depth_model_size: usize) {
+fn export_params<T: FillValue>(result: &mut String, params: &Params2D<T>) {
+    result.push_str(serde_json::to_string(params).unwrap().as_str());
+}
+
+fn export_true_axes<T: FillValue>(result: &mut String, params: &Params2D<T>, axes_export: &[AxisExportType], depth_model_size: usize) {
     *result += "{\"x_ax\":[";
     params.x_axis().export_axis(&axes_export[0], result);
     *result += "],";
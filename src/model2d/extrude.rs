@@ -0,0 +1,79 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model2d::Model2D;
+use crate::model3d::Model3D;
+use crate::types::{Axis, ExtrudeVariationFunc, FillValue};
+use crate::types::generation_params::{Params3D, Provenance2D};
+
+impl<T: FillValue> Model2D<T> {
+    /// Creates 2.5D model by replicating section along y axis. Variation returns depth offset of
+    /// every column, model and mask are moved with borders, values outside section repeat its
+    /// top or bottom. Layers params are taken from source 3D params for slices
+    pub fn extrude(&self, y_axis: Axis, variation: Option<ExtrudeVariationFunc>) -> Result<Model3D<T>, &'static str> {
+        #[cfg(debug_assertions)]
+        trace!("Extruding 2D model along y axis");
+
+        let x_size = self.borders.len();
+        if x_size != self.params.x_axis().blocks_count() {
+            return Err("X axis of 2D model doesn't match its traces")
+        }
+
+        let y_size = y_axis.blocks_count();
+        let layers_count = self.borders.first().map_or(0, |trace| trace.len());
+        let depth_size = self.model.first().map(|trace| trace.len())
+            .or(self.model_mask.first().map(|trace| trace.len()))
+            .unwrap_or(0);
+        let offset = |x_num: usize, y_num: usize| variation.map_or(0, |variation| variation(x_num, y_num));
+        // Borders can't go below the model, so they keep matching the moved mask
+        let max_border = if depth_size != 0 { depth_size as i32 } else { i32::MAX };
+
+        // Borders of 3D model are stored as Z->Y->X
+        let borders: Vec<Vec<Vec<i32>>> = (0..layers_count).map(|layer| {
+            (0..y_size).map(|y_num| {
+                (0..x_size).map(|x_num| (self.borders[x_num][layer] + offset(x_num, y_num)).clamp(0, max_border)).collect()
+            }).collect()
+        }).collect();
+
+        let model: Vec<Vec<Vec<T>>> = self.model.iter().enumerate().map(|(x_num, trace)| {
+            (0..y_size).map(|y_num| shift_trace(trace, offset(x_num, y_num))).collect()
+        }).collect();
+
+        let model_mask: Vec<Vec<Vec<u8>>> = self.model_mask.iter().enumerate().map(|(x_num, trace)| {
+            (0..y_size).map(|y_num| shift_trace(trace, offset(x_num, y_num))).collect()
+        }).collect();
+
+        let mut params: Params3D<T> = Params3D::with_value_type();
+        match self.params.provenance() {
+            Provenance2D::Slice3D { source_params, .. } => {
+                params.set_layers_dist(source_params.layers_dist().clone());
+                params.set_layers_border(source_params.layers_border().clone());
+                params.set_layers_fill(source_params.layers_fill().clone());
+                params.set_layers_info(source_params.layers_info().clone());
+            },
+            Provenance2D::Generated => {
+                params.set_layers_dist(self.params.layers_dist().clone());
+                params.set_layers_border(self.params.layers_border().clone());
+                params.set_layers_fill(self.params.layers_fill().clone());
+            },
+        }
+        params.set_x_axis(self.params.x_axis().clone());
+        params.set_y_axis(y_axis);
+        params.set_model_needed(!model.is_empty());
+        params.set_mask_needed(!model_mask.is_empty());
+
+        let max_depth = if depth_size != 0 {
+            depth_size as i32
+        } else {
+            borders.iter().flatten().flatten().copied().max().unwrap_or(0)
+        };
+
+        Ok(Model3D::new(model, model_mask, borders, self.layers_filling_values.clone(), max_depth, params))
+    }
+}
+
+/// Moves trace down by offset (up if it's negative) keeping its size
+fn shift_trace<V: Copy>(trace: &[V], offset: i32) -> Vec<V> {
+    let last = trace.len() as i32 - 1;
+    (0..trace.len() as i32).map(|z_num| trace[(z_num - offset).clamp(0, last) as usize]).collect()
+}
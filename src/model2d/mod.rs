@@ -1,32 +1,80 @@
-use crate::types::generation_params::Params2D;
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model3d;
+use crate::types::{Axis, FillValue, GeologicalEvent};
+use crate::types::generation_params::{Params2D, Params3D};
 
 pub mod export;
+pub mod extrude;
+
+/// Generates 2D section: borders, shifts and fill. Section is generated like 3D model with one block
+/// along y axis, shifts are applied in the same order as they were added
+pub fn generate_model<T: FillValue>(params: Params2D<T>) -> Result<Model2D<T>, &'static str> {
+    #[cfg(debug_assertions)]
+    trace!("Starting generating 2D model");
+
+    let mut section_params: Params3D<T> = Params3D::with_value_type();
+    section_params.set_x_axis(params.x_axis().clone());
+    section_params.set_y_axis(Axis::generate_axis(0.0, 1.0, None).map_err(|_| "Could not create axis for section")?);
+    section_params.set_layers_dist(params.layers_dist().clone());
+    section_params.set_layers_border(params.layers_border().clone());
+    section_params.set_layers_fill(params.layers_fill().clone());
+    section_params.set_model_needed(params.model_needed());
+    section_params.set_mask_needed(params.mask_needed());
+
+    section_params.add_event(GeologicalEvent::Deposit { layers_count: params.layers_dist().get_layers_count() });
+    for shift in params.shifts() {
+        if let Some(fault) = shift.to_fault()? {
+            section_params.add_event(GeologicalEvent::Fault(fault));
+        } else if let Some(block) = shift.to_rift_block()? {
+            section_params.add_event(GeologicalEvent::RiftBlock(block));
+        }
+    }
+
+    let section = model3d::generate_model(section_params)?;
+
+    // Borders of 2D model are stored as X->Z
+    let borders: Vec<Vec<i32>> = (0..params.x_axis().blocks_count())
+        .map(|x_num| section.borders().iter().map(|border| border[0][x_num]).collect())
+        .collect();
+    let model: Vec<Vec<T>> = section.model().iter().map(|x_line| x_line[0].clone()).collect();
+    let model_mask: Vec<Vec<u8>> = section.model_mask().iter().map(|x_line| x_line[0].clone()).collect();
+
+    #[cfg(debug_assertions)]
+    trace!("2D model was generated");
+
+    Ok(Model2D::new(model, model_mask, borders, section.layers_filling_values().clone(), params))
+}
 
 #[derive(Debug, Clone)]
-pub struct Model2D {
-    model: Vec<Vec<i32>>,
+pub struct Model2D<T = i32> {
+    model: Vec<Vec<T>>,
     model_mask: Vec<Vec<u8>>,
     borders: Vec<Vec<i32>>,
-    params: Params2D,
+    layers_filling_values: Vec<Vec<T>>,
+    params: Params2D<T>,
 }
 
-impl Model2D {
+impl<T: FillValue> Model2D<T> {
     pub fn new(
-        model: Vec<Vec<i32>>,
+        model: Vec<Vec<T>>,
         model_mask: Vec<Vec<u8>>,
         borders: Vec<Vec<i32>>,
-        params: Params2D) -> Model2D {
+        layers_filling_values: Vec<Vec<T>>,
+        params: Params2D<T>) -> Model2D<T> {
         Model2D {
             model,
             model_mask,
             borders,
+            layers_filling_values,
             params,
         }
     } 
 }
 
-impl Model2D {
-    pub fn model(&self) -> &Vec<Vec<i32>> {
+impl<T: FillValue> Model2D<T> {
+    pub fn model(&self) -> &Vec<Vec<T>> {
         &self.model
     }
 
@@ -34,7 +82,16 @@ impl Model2D {
         &self.model_mask
     }
     
+    /// Returns borders of layers for every x block (X->Z)
     pub fn borders(&self) -> &Vec<Vec<i32>> {
         &self.borders
     }
+
+    pub fn layers_filling_values(&self) -> &Vec<Vec<T>> {
+        &self.layers_filling_values
+    }
+
+    pub fn params(&self) -> &Params2D<T> {
+        &self.params
+    }
 }
@@ -0,0 +1,62 @@
+use rand::Rng;
+
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model3d::borders3d::random_border;
+use crate::types::{ErosionSurface, FillValue};
+use crate::types::generation_params::Params3D;
+
+/// Creates erosion surface (Y->X like borders), surface never goes above zero depth
+pub fn create_erosion_surface<T: FillValue>(params: &Params3D<T>, erosion_surface: &ErosionSurface) -> Result<Vec<Vec<i32>>, &'static str> {
+    #[cfg(debug_assertions)]
+    trace!("Creating erosion surface: {:?}", erosion_surface);
+
+    let x_centers = params.x_axis().centers();
+    let y_centers = params.y_axis().centers();
+    let mut surface = vec![vec![0i32; x_centers.len()]; y_centers.len()];
+
+    match erosion_surface {
+        ErosionSurface::Flat { depth } => {
+            surface.iter_mut().flatten().for_each(|value| *value = *depth);
+        },
+        ErosionSurface::Random { min_depth, max_depth, max_step } => {
+            if min_depth > max_depth {
+                return Err("Max depth of erosion surface must be bigger or equal min depth")
+            }
+            random_border::random_layer_creation(*max_step, *max_depth, *min_depth, &mut surface)?;
+        },
+        ErosionSurface::Noise { depth, amplitude, wavelength } => {
+            if *wavelength <= 0.0 {
+                return Err("Wavelength of erosion surface must be bigger than zero")
+            }
+            let (x_start, y_start) = (x_centers[0].min(*x_centers.last().unwrap()), y_centers[0].min(*y_centers.last().unwrap()));
+            let x_nodes = ((x_centers[0] - x_centers.last().unwrap()).abs() / wavelength).ceil() as usize + 2;
+            let y_nodes = ((y_centers[0] - y_centers.last().unwrap()).abs() / wavelength).ceil() as usize + 2;
+
+            let mut rng = rand::thread_rng();
+            let nodes: Vec<Vec<f32>> = (0..y_nodes).map(|_| (0..x_nodes).map(|_| rng.gen_range(-1.0..=1.0)).collect()).collect();
+
+            for (y_num, y) in y_centers.iter().enumerate() {
+                for (x_num, x) in x_centers.iter().enumerate() {
+                    let noise = value_noise(&nodes, (x - x_start) / wavelength, (y - y_start) / wavelength);
+                    surface[y_num][x_num] = depth + (amplitude * noise).round() as i32;
+                }
+            }
+        },
+    }
+
+    surface.iter_mut().flatten().for_each(|value| *value = (*value).max(0));
+    Ok(surface)
+}
+
+// Smoothstep interpolation of random values in grid nodes
+fn value_noise(nodes: &[Vec<f32>], x: f32, y: f32) -> f32 {
+    let (x_node, y_node) = (x.floor() as usize, y.floor() as usize);
+    let smooth = |t: f32| t * t * (3.0 - 2.0 * t);
+    let (tx, ty) = (smooth(x - x_node as f32), smooth(y - y_node as f32));
+
+    let lower = nodes[y_node][x_node] * (1.0 - tx) + nodes[y_node][x_node + 1] * tx;
+    let upper = nodes[y_node + 1][x_node] * (1.0 - tx) + nodes[y_node + 1][x_node + 1] * tx;
+    lower * (1.0 - ty) + upper * ty
+}
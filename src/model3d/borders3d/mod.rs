@@ -2,10 +2,12 @@
 use log::{trace, info, error};
 
 pub mod random_border;
+pub mod erosion_surface;
 
+use crate::types::FillValue;
 use crate::types::generation_params::Params3D;
 
-pub fn create_layers_borders_3d(params: &Params3D) -> Result<Vec<Vec<Vec<i32>>>, &'static str> {
+pub fn create_layers_borders_3d<T: FillValue>(params: &Params3D<T>) -> Result<Vec<Vec<Vec<i32>>>, &'static str> {
     #[cfg(debug_assertions)]
     trace!("Starting creating 3D borders");
 
@@ -6,6 +6,7 @@ use log::{trace, error};
 
 const REGENERATE_TRIES:i32 = 500;
 
+#[allow(clippy::unnecessary_unwrap)]
 pub fn random_layer_creation(
     max_step: Option<i32>,
     upper_limit: i32,
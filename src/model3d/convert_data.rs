@@ -1,17 +1,18 @@
 use crate::model3d::Model3D;
+use crate::model3d::depth_slice::DepthSlice;
 use crate::model2d::Model2D;
-use crate::types::generation_params::Params2D;
-use crate::types::Axis; 
+use crate::types::generation_params::{Params2D, Provenance2D};
+use crate::types::{Axis, FillValue, Interpolation};
 
-impl Model3D {
-    pub fn get_by_num(&self, x: usize, y: usize) -> Result<Vec<i32>, &'static str> {
+impl<T: FillValue> Model3D<T> {
+    pub fn get_by_num(&self, x: usize, y: usize) -> Result<Vec<T>, &'static str> {
         if self.model.is_empty() { return Err("Model doesn't exists in object") };
-        if self.model.len() < x || self.model[0].len() < y { return Err("X or Y out of bounds") };
+        if x >= self.model.len() || y >= self.model[0].len() { return Err("X or Y out of bounds") };
 
         Ok(self.model[x][y].clone())
     }
 
-    pub fn to_model_2d_by_angle(&self, pos_x: f32, angle: f32, resolution: usize) -> Result<Model2D, &'static str> {
+    pub fn to_model_2d_by_angle(&self, pos_x: f32, angle: f32, resolution: usize) -> Result<Model2D<T>, &'static str> {
         let angle = (angle * 1000.0).round() / 1000.0;
         let is_acute = angle < 90.0;
 
@@ -67,8 +68,8 @@ impl Model3D {
         let mut nums_y: Vec<usize> = Vec::with_capacity(resolution);
 
         for _ in 0..(resolution) {
-            nums_x.push(x_ax_obj.find_element_smaller(now_x).unwrap());
-            nums_y.push(y_ax_obj.find_element_smaller(now_y).unwrap());
+            nums_x.push(x_ax_obj.find_element_smaller(now_x).ok_or("Slice line is outside of model")?);
+            nums_y.push(y_ax_obj.find_element_smaller(now_y).ok_or("Slice line is outside of model")?);
 
             now_x = ((now_x + delt_x) * 1000.0).round() / 1000.0;
             now_y = ((now_y + delt_y) * 1000.0).round() / 1000.0;
@@ -77,11 +78,71 @@ impl Model3D {
         self.form_2d_by_nums(nums_x, nums_y)
     }
 
-    pub fn form_2d_by_nums(&self, nums_x: Vec<usize>, nums_y: Vec<usize>) -> Result<Model2D, &'static str> {
+    pub fn form_2d_by_nums(&self, nums_x: Vec<usize>, nums_y: Vec<usize>) -> Result<Model2D<T>, &'static str> {
         if nums_x.len() != nums_y.len() {
             return Err("Vectors cords_x and cords_y must be with same size")
         }
 
+        if nums_x.is_empty() {
+            return Err("Vectors cords_x and cords_y must contain at least one value")
+        }
+
+        let x_ax: Vec<f32> = (0..=nums_x.len()).map(|num| num as f32).collect();
+        self.slice_by_nums(&nums_x, &nums_y, Axis::create_from_edges(x_ax).map_err(|_| "Could not create axis for slice")?)
+    }
+
+    /// Returns inline (section along x axis with fixed y), x axis of section is x axis of model.
+    /// Sections of models with thrusts are not supported, because their borders don't contain thrusts
+    pub fn inline(&self, y_num: usize) -> Result<Model2D<T>, &'static str> {
+        let x_size = self.params.x_axis().blocks_count();
+        self.slice_by_nums(&(0..x_size).collect::<Vec<usize>>(), &vec![y_num; x_size], self.params.x_axis().clone())
+    }
+
+    /// Returns crossline (section along y axis with fixed x), x axis of section is y axis of model.
+    /// Sections of models with thrusts are not supported
+    pub fn crossline(&self, x_num: usize) -> Result<Model2D<T>, &'static str> {
+        let y_size = self.params.y_axis().blocks_count();
+        self.slice_by_nums(&vec![x_num; y_size], &(0..y_size).collect::<Vec<usize>>(), self.params.y_axis().clone())
+    }
+
+    /// Returns horizontal slice of model and mask at depth sample `z_num`
+    pub fn depth_slice(&self, z_num: usize) -> Result<DepthSlice<T>, &'static str> {
+        let model_ex: bool = !self.model.is_empty();
+        let mask_ex: bool = !self.model_mask.is_empty();
+
+        if !model_ex && !mask_ex {
+            return Err("Model and mask don't exist in object")
+        }
+
+        let depth_size = if model_ex { self.model[0][0].len() } else { self.model_mask[0][0].len() };
+        if z_num >= depth_size {
+            return Err("Z out of bounds")
+        }
+
+        let model: Vec<Vec<T>> = if model_ex {
+            self.model.iter().map(|x_line| x_line.iter().map(|column| column[z_num]).collect()).collect()
+        } else {
+            Vec::new()
+        };
+
+        let model_mask: Vec<Vec<u8>> = if mask_ex {
+            self.model_mask.iter().map(|x_line| x_line.iter().map(|column| column[z_num]).collect()).collect()
+        } else {
+            Vec::new()
+        };
+
+        Ok(DepthSlice::new(
+            model,
+            model_mask,
+            z_num,
+            self.params.x_axis().clone(),
+            self.params.y_axis().clone(),
+        ))
+    }
+
+    fn slice_by_nums(&self, nums_x: &[usize], nums_y: &[usize], x_axis: Axis) -> Result<Model2D<T>, &'static str> {
+        self.check_borders_describe_model()?;
+
         let source_model = &self.model;
         let source_model_mask = &self.model_mask;
 
@@ -94,9 +155,9 @@ impl Model3D {
         let mask_ex: bool = !self.model_mask.is_empty();
 
         let mut borders: Vec<Vec<i32>> = Vec::with_capacity(nums_x.len());
-        let mut model: Vec<Vec<i32>> = Vec::with_capacity(if model_ex {nums_x.len()} else {0});
+        let mut model: Vec<Vec<T>> = Vec::with_capacity(if model_ex {nums_x.len()} else {0});
         let mut model_mask: Vec<Vec<u8>> = Vec::with_capacity(if mask_ex {nums_x.len()} else {0});
-        let mut x_ax: Vec<f32> = Vec::with_capacity(nums_x.len());
+        let mut line: Vec<[f32; 2]> = Vec::with_capacity(nums_x.len());
 
         for (num, x_num) in nums_x.iter().enumerate() {
             let y_num = nums_y[num];
@@ -105,7 +166,7 @@ impl Model3D {
                 return Err("Invalid coodinates: it must be smaller than model size")
             }
 
-            x_ax.push(num as f32);
+            line.push([self.params.x_axis().centers()[*x_num], self.params.y_axis().centers()[y_num]]);
 
             let mut borders_temp_vec: Vec<i32> = Vec::with_capacity(borders_model_size_z);
             for layer in &self.borders {
@@ -122,14 +183,151 @@ impl Model3D {
             }
         }
 
-        let mut params = Params2D::new();
-        params.set_x_axis(Axis::create_from_edges(x_ax).unwrap());
+        let params = self.slice_params(x_axis, line, nums_x.len());
+
+        Ok(Model2D::new(
+            model,
+            model_mask,
+            borders,
+            self.layers_filling_values.clone(),
+            params,
+        ))
+    }
+
+    /// Creates section along polyline (points x, y in axis units), e.g. seismic line or well
+    /// correlation path. Traces are sampled every `spacing` along the line in the middle of their
+    /// blocks, so x axis of section is distance along the line. Mask is categorical, so it's taken
+    /// from the column with the biggest weight, while borders are interpolated: with linear
+    /// interpolation mask near layer boundaries can disagree with borders by a few samples.
+    /// Sections of models with thrusts are not supported
+    pub fn section_along(&self, polyline: &[[f32; 2]], spacing: f32, interpolation: Interpolation) -> Result<Model2D<T>, &'static str> {
+        self.check_borders_describe_model()?;
+
+        if polyline.len() < 2 {
+            return Err("Polyline must contain at least two points")
+        }
+
+        if !spacing.is_finite() || spacing <= 0.0 {
+            return Err("Spacing must be bigger than zero")
+        }
+
+        let x_ax_obj = self.params.x_axis();
+        let y_ax_obj = self.params.y_axis();
+
+        // Model is rectangle, so line between points inside it is inside too
+        for point in polyline {
+            if x_ax_obj.find_element_smaller(point[0]).is_none() || y_ax_obj.find_element_smaller(point[1]).is_none() {
+                return Err("Polyline must be inside model")
+            }
+        }
+
+        let segments_len: Vec<f32> = polyline.windows(2)
+            .map(|points| (points[1][0] - points[0][0]).hypot(points[1][1] - points[0][1]))
+            .collect();
+        let line_len: f32 = segments_len.iter().sum();
+
+        if line_len <= 0.0 {
+            return Err("Polyline must have non-zero length")
+        }
+
+        // The last block can be shorter than spacing, tiny last blocks are joined to previous ones
+        let mut edges: Vec<f32> = (0..)
+            .map(|num| num as f32 * spacing)
+            .take_while(|distance| *distance < line_len - spacing * 1e-3)
+            .collect();
+        edges.push(line_len);
+
+        let traces_count = edges.len() - 1;
+        let model_ex: bool = !self.model.is_empty();
+        let mask_ex: bool = !self.model_mask.is_empty();
+
+        let mut borders: Vec<Vec<i32>> = Vec::with_capacity(traces_count);
+        let mut model: Vec<Vec<T>> = Vec::with_capacity(if model_ex {traces_count} else {0});
+        let mut model_mask: Vec<Vec<u8>> = Vec::with_capacity(if mask_ex {traces_count} else {0});
+
+        let mut segment = 0;
+        let mut segment_start = 0.0;
+
+        for trace in 0..traces_count {
+            let distance = (edges[trace] + edges[trace + 1]) / 2.0;
+            while segment < segments_len.len() - 1 && distance > segment_start + segments_len[segment] {
+                segment_start += segments_len[segment];
+                segment += 1;
+            }
+
+            let part = if segments_len[segment] > 0.0 {
+                ((distance - segment_start) / segments_len[segment]).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let (start, end) = (polyline[segment], polyline[segment + 1]);
+            let pos_x = start[0] + (end[0] - start[0]) * part;
+            let pos_y = start[1] + (end[1] - start[1]) * part;
+
+            let columns = match interpolation {
+                Interpolation::Nearest => {
+                    let (x_num, y_num) = (x_ax_obj.nearest_block(pos_x), y_ax_obj.nearest_block(pos_y));
+                    vec![(x_num, y_num, 1.0)]
+                },
+                Interpolation::Linear => {
+                    let (x_first, x_second, x_part) = x_ax_obj.linear_neighbours(pos_x);
+                    let (y_first, y_second, y_part) = y_ax_obj.linear_neighbours(pos_y);
+                    vec![
+                        (x_first, y_first, (1.0 - x_part) * (1.0 - y_part)),
+                        (x_second, y_first, x_part * (1.0 - y_part)),
+                        (x_first, y_second, (1.0 - x_part) * y_part),
+                        (x_second, y_second, x_part * y_part),
+                    ]
+                },
+            };
+
+            borders.push(self.borders.iter().map(|layer| {
+                columns.iter()
+                    .map(|(x_num, y_num, weight)| layer[*y_num][*x_num] as f32 * weight)
+                    .sum::<f32>()
+                    .round() as i32
+            }).collect());
+
+            if model_ex {
+                let depth_size = self.model[0][0].len();
+                model.push((0..depth_size).map(|z_num| {
+                    T::from_f64(columns.iter()
+                        .map(|(x_num, y_num, weight)| self.model[*x_num][*y_num][z_num].to_f64() * *weight as f64)
+                        .sum())
+                }).collect());
+            }
+
+            if mask_ex {
+                let (x_num, y_num, _) = columns.iter()
+                    .fold((0, 0, f32::MIN), |best, column| if column.2 > best.2 { *column } else { best });
+                model_mask.push(self.model_mask[x_num][y_num].clone());
+            }
+        }
+
+        let x_axis = Axis::create_from_edges(edges).map_err(|_| "Spacing is too small for polyline")?;
+        let params = self.slice_params(x_axis, polyline.to_vec(), traces_count);
 
         Ok(Model2D::new(
             model,
             model_mask,
             borders,
+            self.layers_filling_values.clone(),
             params,
         ))
     }
+
+    // Layers params of slice are taken from the source model, so they describe its layers
+    fn slice_params(&self, x_axis: Axis, line: Vec<[f32; 2]>, resolution: usize) -> Params2D<T> {
+        let mut params: Params2D<T> = Params2D::with_value_type();
+        params.set_x_axis(x_axis);
+        params.set_layers_dist(self.params.layers_dist().clone());
+        params.set_layers_border(self.params.layers_border().clone());
+        params.set_layers_fill(self.params.layers_fill().clone());
+        params.set_provenance(Provenance2D::Slice3D {
+            source_params: Box::new(self.params.clone()),
+            line,
+            resolution,
+        });
+        params
+    }
 }
@@ -0,0 +1,91 @@
+use std::fs::File;
+use std::io::Write;
+
+use crate::model2d::export::{export_model_num, export_mask_num};
+use crate::types::{Axis, AxisExportType, FillValue};
+
+/// Horizontal slice of 3D model at one depth sample, model and mask are stored as X->Y
+#[derive(Debug, Clone)]
+pub struct DepthSlice<T = i32> {
+    model: Vec<Vec<T>>,
+    model_mask: Vec<Vec<u8>>,
+    depth: usize,
+    x_ax: Axis,
+    y_ax: Axis,
+}
+
+impl<T: FillValue> DepthSlice<T> {
+    pub fn new(
+        model: Vec<Vec<T>>,
+        model_mask: Vec<Vec<u8>>,
+        depth: usize,
+        x_ax: Axis,
+        y_ax: Axis) -> DepthSlice<T> {
+        DepthSlice {
+            model,
+            model_mask,
+            depth,
+            x_ax,
+            y_ax,
+        }
+    }
+
+    pub fn model(&self) -> &Vec<Vec<T>> {
+        &self.model
+    }
+
+    pub fn model_mask(&self) -> &Vec<Vec<u8>> {
+        &self.model_mask
+    }
+
+    /// Returns depth sample of slice
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn x_axis(&self) -> &Axis {
+        &self.x_ax
+    }
+
+    pub fn y_axis(&self) -> &Axis {
+        &self.y_ax
+    }
+}
+
+impl<T: FillValue> DepthSlice<T> {
+    pub fn export_slice(&self, name: &str, save: &[&str], axes_export: &[AxisExportType]) -> Result<(), std::io::Error> {
+        let default_ax_type = vec![AxisExportType::AsSelf, AxisExportType::AsSelf];
+
+        let axes_export = if axes_export.len() != 2 {
+            eprintln!("Warning: Axes export param is ignored, it must contain 2 elements (for x, y)");
+            &default_ax_type
+        } else {
+            axes_export
+        };
+
+        let mut result = String::from("");
+        result += "{\"depth\":";
+        result += &self.depth.to_string();
+
+        result += ",\"output_axes\":{\"x_ax\":[";
+        self.x_ax.export_axis(&axes_export[0], &mut result);
+        result += "],\"y_ax\":[";
+        self.y_ax.export_axis(&axes_export[1], &mut result);
+        result += "]}";
+
+        result += ",\"model\":";
+        if save.contains(&"model") && !self.model.is_empty() {
+            export_model_num(&mut result, &self.model)
+        } else { result += "null" }
+
+        result += ",\"model_mask\":";
+        if save.contains(&"model_mask") && !self.model_mask.is_empty() {
+            export_mask_num(&mut result, &self.model_mask)
+        } else { result += "null" }
+        result += "}";
+
+        let mut file = File::create(format!("{name}.json"))?;
+        file.write_all(result.as_bytes())?;
+        Ok(())
+    }
+}
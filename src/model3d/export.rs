@@ -5,9 +5,10 @@ use numtoa::NumToA;
 
 use crate::model3d::Model3D;
 use crate::types::generation_params::Params3D;
-use crate::types::{AxisExportType, Axis};
+use crate::types::fill_value::export_fill_values;
+use crate::types::{AxisExportType, Axis, FillValue, GeologicalEvent, LayerInfo};
 
-impl Model3D {
+impl<T: FillValue> Model3D<T> {
     pub fn export_model(&self, name: &str, save: &[&str], axes_export: &Vec<AxisExportType>) -> Result<(), std::io::Error> {
         let default_ax_type = vec![AxisExportType::AsSelf, AxisExportType::AsSelf, AxisExportType::AsSelf];
 
@@ -25,6 +26,11 @@ impl Model3D {
             export_params(&mut result, &self.params);
         } else { result += "null" }
 
+        result += ",\"layers_info\":";
+        if save.contains(&"layers_info") {
+            export_layers_info(&mut result, &self.params, self.borders.len())
+        } else { result += "null" }
+
         result += ",\"output_axes\":";
         export_true_axes(&mut result, &self.params, axes_export, self.max_depth);
 
@@ -35,7 +41,7 @@ impl Model3D {
 
         result += ",\"fill_values\":";
         if save.contains(&"fill_values") {
-            export_border_num(&mut result, &self.borders)
+            export_fill_values(&mut result, &self.layers_filling_values)
         } else { result += "null" }
 
         result += ",\"model\":";
@@ -47,6 +53,31 @@ impl Model3D {
         if save.contains(&"model_mask") {
             export_mask_num(&mut result, &self.model_mask)
         } else { result += "null" }
+
+        result += ",\"fluid_mask\":";
+        if save.contains(&"fluid_mask") && !self.fluid_mask.is_empty() {
+            export_mask_num(&mut result, &self.fluid_mask)
+        } else { result += "null" }
+
+        result += ",\"fault_mask\":";
+        if save.contains(&"fault_mask") && !self.fault_mask.is_empty() {
+            export_mask_num(&mut result, &self.fault_mask)
+        } else { result += "null" }
+
+        result += ",\"fault_distance\":";
+        if save.contains(&"fault_distance") && !self.fault_distance.is_empty() {
+            export_model_num(&mut result, &self.fault_distance)
+        } else { result += "null" }
+
+        result += ",\"fault_blocks\":";
+        if save.contains(&"fault_blocks") && !self.fault_blocks.is_empty() {
+            export_mask_num(&mut result, &self.fault_blocks)
+        } else { result += "null" }
+
+        result += ",\"intervals\":";
+        if save.contains(&"intervals") && !self.intervals.is_empty() {
+            result.push_str(serde_json::to_string(&self.intervals).unwrap().as_str());
+        } else { result += "null" }
         result += "}";
 
         if name == "TestModelBench.test.bench" { return Ok(()) }
@@ -88,8 +119,7 @@ fn export_border_num(result: &mut String, borders: &[Vec<Vec<i32>>]){
     *result += "]";
 }
 
-fn export_model_num(result: &mut String, model: &[Vec<Vec<i32>>]) {
-    let mut buf = [0u8; 12];
+fn export_model_num<T: FillValue>(result: &mut String, model: &[Vec<Vec<T>>]) {
     *result += "[";
 
     for (x_num, x_ax) in model.iter().enumerate() {
@@ -100,11 +130,11 @@ fn export_model_num(result: &mut String, model: &[Vec<Vec<i32>>]) {
             *result += "{\"y";
             *result += format!("{y_num}\":[").as_str();
 
-            result.push_str(y_ax[0].numtoa_str(10, &mut buf));
+            y_ax[0].export_value(result);
 
             for depth in y_ax[1..].iter() {
                 result.push(',');
-                result.push_str(depth.numtoa_str(10, &mut buf));
+                depth.export_value(result);
             }
 
             if y_num != x_ax.len() - 1 {
@@ -123,7 +153,7 @@ fn export_model_num(result: &mut String, model: &[Vec<Vec<i32>>]) {
     *result += "]";
 }
 
-fn export_mask_num(result: &mut String, model_mask: &[Vec<Vec<u8>>]) {
+fn export_mask_num<M: NumToA<M> + Copy + From<u8>>(result: &mut String, model_mask: &[Vec<Vec<M>>]) {
     let mut buf = [0u8; 12];
     *result += "[";
 
@@ -135,11 +165,11 @@ fn export_mask_num(result: &mut String, model_mask: &[Vec<Vec<u8>>]) {
             *result += "{\"y";
             *result += format!("{y_num}\":[").as_str();
 
-            result.push_str(y_ax[0].numtoa_str(10, &mut buf));
+            result.push_str(y_ax[0].numtoa_str(M::from(10), &mut buf));
 
             for depth in y_ax[1..].iter() {
                 result.push(',');
-                result.push_str(depth.numtoa_str(10, &mut buf));
+                result.push_str(depth.numtoa_str(M::from(10), &mut buf));
             }
 
             if y_num != x_ax.len() - 1 {
@@ -158,11 +188,26 @@ fn export_mask_num(result: &mut String, model_mask: &[Vec<Vec<u8>>]) {
     *result += "]";
 }
 
-fn export_params(result: &mut String, params: &Params3D) {
+fn export_params<T: FillValue>(result: &mut String, params: &Params3D<T>) {
     result.push_str(serde_json::to_string(params).unwrap().as_str());
 }
 
-fn export_true_axes(result: &mut String, params: &Params3D, axes_export: &[AxisExportType], depth_model_size: i32) {
+fn export_layers_info<T: FillValue>(result: &mut String, params: &Params3D<T>, layers_count: usize) {
+    let default_info = LayerInfo::new();
+    let mut layers_info: Vec<&LayerInfo> = (0..layers_count)
+        .map(|layer| params.layer_info(layer).unwrap_or(&default_info))
+        .collect();
+
+    // Intrusions have mask value equal to layers count, so they get the last entry
+    let intrusion_info = LayerInfo::with_name("intrusion");
+    if params.events().iter().any(|event| matches!(event, GeologicalEvent::Intrude(_))) {
+        layers_info.push(&intrusion_info);
+    }
+
+    result.push_str(serde_json::to_string(&layers_info).unwrap().as_str());
+}
+
+fn export_true_axes<T: FillValue>(result: &mut String, params: &Params3D<T>, axes_export: &[AxisExportType], depth_model_size: i32) {
     *result += "{\"x_ax\":[";
     params.x_axis().export_axis(&axes_export[0], result);
     *result += "],";
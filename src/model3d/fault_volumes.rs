@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+#[cfg(debug_assertions)]
+use log::trace;
+
+/// Surface of one fault for every column (Y->X like borders): depth where fault crosses the column
+/// and whether hanging wall is above this depth. Infinite depth means column is not crossed
+pub type FaultColumns = Vec<Vec<(f32, bool)>>;
+
+type FaultVolumesType = (Vec<Vec<Vec<u16>>>, Vec<Vec<Vec<f32>>>, Vec<Vec<Vec<u16>>>);
+
+// Columns which are not crossed by fault are kept far below the model while surfaces are moved
+const NOT_CROSSED_BELOW: i32 = 1 << 29;
+const NOT_CROSSED_ABOVE: i32 = 1 << 28;
+
+/// Moves surfaces of existing faults by later event. `move_borders` gets surfaces as borders together
+/// with a copy of max depth, so it has to move them the same way as borders of existing layers.
+pub fn move_fault_columns<F>(faults_columns: &mut [FaultColumns], max_depth: i32, move_borders: F) -> Result<(), &'static str>
+where
+    F: FnOnce(&mut [Vec<Vec<i32>>], &mut i32) -> Result<(), &'static str>
+{
+    if faults_columns.is_empty() {
+        return Ok(())
+    }
+
+    let mut surfaces: Vec<Vec<Vec<i32>>> = faults_columns.iter().map(|fault| {
+        fault.iter().map(|y_line| {
+            y_line.iter().map(|(depth, _)| match *depth {
+                depth if depth == f32::INFINITY => NOT_CROSSED_BELOW,
+                depth if depth == f32::NEG_INFINITY => NOT_CROSSED_ABOVE,
+                depth => depth.floor() as i32,
+            }).collect()
+        }).collect()
+    }).collect();
+
+    let mut surfaces_depth = max_depth;
+    move_borders(&mut surfaces, &mut surfaces_depth)?;
+
+    for (fault, surface) in faults_columns.iter_mut().zip(&surfaces) {
+        for (y_line, surface_line) in fault.iter_mut().zip(surface) {
+            for ((depth, _), value) in y_line.iter_mut().zip(surface_line) {
+                *depth = if *value >= (NOT_CROSSED_BELOW + NOT_CROSSED_ABOVE) / 2 {
+                    f32::INFINITY
+                } else if *value >= NOT_CROSSED_ABOVE / 2 {
+                    f32::NEG_INFINITY
+                } else if depth.is_finite() {
+                    *value as f32 + depth.fract()
+                } else {
+                    // Strike-slip fault can bring crossed part of surface to this column
+                    *value as f32 + 0.5
+                };
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates fault mask (fault id + 1, 0 without fault), distance to nearest fault (in blocks) and
+/// fault block id volumes, all of them are stored as X->Y->Z like model. Distance is empty if there
+/// is no fault inside the model.
+/// Note: fault surfaces should be moved by later events with `move_fault_columns` before calling it.
+pub fn create_fault_volumes(
+    faults: &[FaultColumns],
+    x_size: usize,
+    y_size: usize,
+    depth_size: usize
+) -> Result<FaultVolumesType, &'static str> {
+    #[cfg(debug_assertions)]
+    trace!("Creating fault volumes for {} faults", faults.len());
+
+    if faults.len() >= u16::MAX as usize {
+        return Err("Program do not support models with more than 65534 faults")
+    }
+
+    let is_hanging_wall = |fault: &FaultColumns, x: usize, y: usize, z: usize| -> bool {
+        let (depth, hanging_above) = fault[y][x];
+        ((z as f32) < depth) == hanging_above
+    };
+
+    let mut fault_mask = vec![vec![vec![0u16; depth_size]; y_size]; x_size];
+    let mut fault_blocks = vec![vec![vec![0u16; depth_size]; y_size]; x_size];
+
+    for (fault_num, fault) in faults.iter().enumerate() {
+        let fault_id = fault_num as u16 + 1;
+        // Every block of previous faults is split into two blocks by sides of this fault
+        let mut blocks_ids: HashMap<(u16, bool), u16> = HashMap::new();
+
+        for x in 0..x_size {
+            for y in 0..y_size {
+                for z in 0..depth_size {
+                    let side = is_hanging_wall(fault, x, y, z);
+
+                    let key = (fault_blocks[x][y][z], side);
+                    fault_blocks[x][y][z] = match blocks_ids.get(&key) {
+                        Some(block_id) => *block_id,
+                        None => {
+                            if blocks_ids.len() > u16::MAX as usize {
+                                return Err("Program do not support models with more than 65536 fault blocks")
+                            }
+                            let block_id = blocks_ids.len() as u16;
+                            blocks_ids.insert(key, block_id);
+                            block_id
+                        }
+                    };
+
+                    let is_fault = (x + 1 < x_size && is_hanging_wall(fault, x + 1, y, z) != side)
+                        || (x > 0 && is_hanging_wall(fault, x - 1, y, z) != side)
+                        || (y + 1 < y_size && is_hanging_wall(fault, x, y + 1, z) != side)
+                        || (y > 0 && is_hanging_wall(fault, x, y - 1, z) != side)
+                        || (z + 1 < depth_size && is_hanging_wall(fault, x, y, z + 1) != side)
+                        || (z > 0 && is_hanging_wall(fault, x, y, z - 1) != side);
+
+                    if is_fault {
+                        fault_mask[x][y][z] = fault_id;
+                    }
+                }
+            }
+        }
+    }
+
+    let fault_distance = distance_to_faults(&fault_mask);
+
+    Ok((fault_mask, fault_distance, fault_blocks))
+}
+
+/// Chamfer distance transform (two passes over 26 neighbours), distance is measured in blocks.
+/// Returns empty vec if there is no fault inside the mask
+pub(crate) fn distance_to_faults(fault_mask: &[Vec<Vec<u16>>]) -> Vec<Vec<Vec<f32>>> {
+    if !fault_mask.iter().flatten().flatten().any(|id| *id != 0) {
+        return Vec::new()
+    }
+
+    let (x_size, y_size, depth_size) = (fault_mask.len(), fault_mask[0].len(), fault_mask[0][0].len());
+
+    let mut distance: Vec<Vec<Vec<f32>>> = fault_mask.iter().map(|x_mask| {
+        x_mask.iter().map(|y_mask| {
+            y_mask.iter().map(|value| if *value != 0 { 0.0 } else { f32::MAX }).collect()
+        }).collect()
+    }).collect();
+
+    // Neighbours which are visited before the block in forward pass
+    let mut forward: Vec<(i64, i64, i64, f32)> = Vec::with_capacity(13);
+    for dx in -1i64..=1 {
+        for dy in -1i64..=1 {
+            for dz in -1i64..=1 {
+                if (dx, dy, dz) < (0, 0, 0) {
+                    forward.push((dx, dy, dz, ((dx * dx + dy * dy + dz * dz) as f32).sqrt()));
+                }
+            }
+        }
+    }
+
+    let sizes = (x_size as i64, y_size as i64, depth_size as i64);
+    let relax = |distance: &mut [Vec<Vec<f32>>], x: usize, y: usize, z: usize, sign: i64| {
+        for (dx, dy, dz, weight) in &forward {
+            let (nx, ny, nz) = (x as i64 + sign * dx, y as i64 + sign * dy, z as i64 + sign * dz);
+            if nx < 0 || ny < 0 || nz < 0 || nx >= sizes.0 || ny >= sizes.1 || nz >= sizes.2 {
+                continue;
+            }
+            let candidate = distance[nx as usize][ny as usize][nz as usize] + weight;
+            if candidate < distance[x][y][z] {
+                distance[x][y][z] = candidate;
+            }
+        }
+    };
+
+    for x in 0..x_size {
+        for y in 0..y_size {
+            for z in 0..depth_size {
+                relax(&mut distance, x, y, z, 1);
+            }
+        }
+    }
+
+    for x in (0..x_size).rev() {
+        for y in (0..y_size).rev() {
+            for z in (0..depth_size).rev() {
+                relax(&mut distance, x, y, z, -1);
+            }
+        }
+    }
+
+    distance
+}
@@ -4,8 +4,10 @@ use log::trace;
 use rand::distributions::Distribution;
 
 use crate::model3d::fill3d::GenerationTypes;
+use crate::model3d::intervals3d::ColumnsIntervals;
+use crate::types::FillValue;
 
-type ModelAndMaskType = (Vec<Vec<Vec<i32>>>, Vec<Vec<Vec<u8>>>);
+type ModelAndMaskType<T> = (Vec<Vec<Vec<T>>>, Vec<Vec<Vec<u8>>>);
 
 fn generate_consts(borders: &Vec<Vec<Vec<i32>>>) -> (i32, usize, usize, usize) {
     let mut max_elem = 0;
@@ -22,28 +24,30 @@ fn generate_consts(borders: &Vec<Vec<Vec<i32>>>) -> (i32, usize, usize, usize) {
     (max_elem, borders.len(), borders[0].len(), borders[0][0].len())
 }
 
-pub fn create_full_model_with_mask(
+// Borders are stored as Z->Y->X, so column coords are only used as indexes
+#[allow(clippy::needless_range_loop)]
+pub fn create_full_model_with_mask<T: FillValue>(
     borders: &Vec<Vec<Vec<i32>>>,
-    fill_values: &[GenerationTypes]
-) -> ModelAndMaskType {
+    fill_values: &[GenerationTypes<T>]
+) -> ModelAndMaskType<T> {
     #[cfg(debug_assertions)]
     trace!("Starting filling model: model and mask");
 
     let (max_elem, layers_count, y_size, x_size) = generate_consts(borders);
     let max_elem: usize = max_elem.try_into().unwrap();
 
-    let mut model: Vec<Vec<Vec<i32>>> = Vec::with_capacity(x_size);
+    let mut model: Vec<Vec<Vec<T>>> = Vec::with_capacity(x_size);
     let mut model_mask: Vec<Vec<Vec<u8>>> = Vec::with_capacity(x_size);
     let mut rng = rand::thread_rng();
 
     for x_cord in 0..x_size {
 
-        let mut now_x: Vec<Vec<i32>> = Vec::with_capacity(y_size);
+        let mut now_x: Vec<Vec<T>> = Vec::with_capacity(y_size);
         let mut now_x_mask: Vec<Vec<u8>> = Vec::with_capacity(y_size);
 
         for y_cord in 0..y_size {
 
-            let mut now_y: Vec<i32> = Vec::with_capacity(max_elem);
+            let mut now_y: Vec<T> = Vec::with_capacity(max_elem);
             let mut now_y_mask: Vec<u8> = Vec::with_capacity(max_elem);
 
             let mut now_index: usize = 0;
@@ -65,7 +69,7 @@ pub fn create_full_model_with_mask(
 
                 now_y.push(match fill_values[now_index] {
                     GenerationTypes::GenerationExact(value) => value,
-                    GenerationTypes::GenerationRange(generation_range) => generation_range.sample(&mut rng)
+                    GenerationTypes::GenerationRange(ref generation_range) => generation_range.sample(&mut rng)
                 });
                 now_y_mask.push(now_index_u8);
             }
@@ -84,26 +88,27 @@ pub fn create_full_model_with_mask(
     (model, model_mask)
 }
 
-pub fn create_full_model_without_mask(
+#[allow(clippy::needless_range_loop)]
+pub fn create_full_model_without_mask<T: FillValue>(
     borders: &Vec<Vec<Vec<i32>>>,
-    fill_values: &[GenerationTypes]
-) -> Vec<Vec<Vec<i32>>> {
+    fill_values: &[GenerationTypes<T>]
+) -> Vec<Vec<Vec<T>>> {
     #[cfg(debug_assertions)]
     trace!("Starting filling only model");
 
     let (max_elem, layers_count, y_size, x_size) = generate_consts(borders);
     let max_elem: usize = max_elem.try_into().unwrap();
 
-    let mut model: Vec<Vec<Vec<i32>>> = Vec::with_capacity(x_size);
+    let mut model: Vec<Vec<Vec<T>>> = Vec::with_capacity(x_size);
     let mut rng = rand::thread_rng();
 
     for x_cord in 0..x_size {
 
-        let mut now_x: Vec<Vec<i32>> = Vec::with_capacity(y_size);
+        let mut now_x: Vec<Vec<T>> = Vec::with_capacity(y_size);
 
         for y_cord in 0..y_size {
 
-            let mut now_y: Vec<i32> = Vec::with_capacity(max_elem);
+            let mut now_y: Vec<T> = Vec::with_capacity(max_elem);
 
             let mut now_index: usize = 0;
             let mut now_depth: usize = borders[now_index][y_cord][x_cord].try_into().unwrap_or(0);
@@ -122,7 +127,7 @@ pub fn create_full_model_without_mask(
 
                 now_y.push(match fill_values[now_index] {
                     GenerationTypes::GenerationExact(value) => value,
-                    GenerationTypes::GenerationRange(generation_range) => generation_range.sample(&mut rng)
+                    GenerationTypes::GenerationRange(ref generation_range) => generation_range.sample(&mut rng)
                 });
             }
 
@@ -138,6 +143,7 @@ pub fn create_full_model_without_mask(
     model
 }
 
+#[allow(clippy::needless_range_loop)]
 pub fn create_only_mask(
     borders: &Vec<Vec<Vec<i32>>>,
 ) -> Vec<Vec<Vec<u8>>> {
@@ -188,3 +194,82 @@ pub fn create_only_mask(
 
     model_mask
 }
+
+/// Fills model and mask from layer intervals, model is empty if it's not needed.
+/// Depths below the last interval of column belong to its last layer
+pub fn create_model_from_intervals<T: FillValue>(
+    intervals: &ColumnsIntervals,
+    fill_values: &[GenerationTypes<T>],
+    depth_size: usize,
+    model_needed: bool,
+) -> ModelAndMaskType<T> {
+    #[cfg(debug_assertions)]
+    trace!("Starting filling model from intervals");
+
+    let (y_size, x_size) = (intervals.len(), intervals[0].len());
+    let mut model: Vec<Vec<Vec<T>>> = Vec::with_capacity(if model_needed { x_size } else { 0 });
+    let mut model_mask: Vec<Vec<Vec<u8>>> = Vec::with_capacity(x_size);
+    let mut rng = rand::thread_rng();
+
+    for x_cord in 0..x_size {
+        let mut now_x: Vec<Vec<T>> = Vec::with_capacity(y_size);
+        let mut now_x_mask: Vec<Vec<u8>> = Vec::with_capacity(y_size);
+
+        for y_column in intervals.iter().take(y_size) {
+            let column = &y_column[x_cord];
+            let mut now_y_mask: Vec<u8> = Vec::with_capacity(depth_size);
+
+            let mut interval_num = 0;
+            for depth in 0..depth_size {
+                while interval_num < column.len() - 1 && depth as i32 >= column[interval_num].base() {
+                    interval_num += 1;
+                }
+                now_y_mask.push(column[interval_num].layer() as u8);
+            }
+
+            if model_needed {
+                now_x.push(now_y_mask.iter().map(|layer| match fill_values[*layer as usize] {
+                    GenerationTypes::GenerationExact(value) => value,
+                    GenerationTypes::GenerationRange(ref generation_range) => generation_range.sample(&mut rng)
+                }).collect());
+            }
+            now_x_mask.push(now_y_mask);
+        }
+
+        if model_needed {
+            model.push(now_x);
+        }
+        model_mask.push(now_x_mask);
+    }
+
+    #[cfg(debug_assertions)]
+    trace!("Model was filled from intervals succesfully");
+
+    (model, model_mask)
+}
+
+/// Returns index of layer for every depth of one column, same as column of model mask
+pub fn column_mask(borders: &[Vec<Vec<i32>>], x_cord: usize, y_cord: usize, depth_size: usize) -> Vec<u8> {
+    let layers_count = borders.len();
+    let mut column: Vec<u8> = Vec::with_capacity(depth_size);
+
+    let mut now_index: usize = 0;
+    let mut now_index_u8: u8 = 0;
+    let mut now_depth: usize = borders[now_index][y_cord][x_cord].try_into().unwrap_or(0);
+
+    for depth in 0..depth_size {
+        if depth >= now_depth && now_index < layers_count - 1 {
+            loop {
+                now_index += 1;
+                now_depth = borders[now_index][y_cord][x_cord].try_into().unwrap_or(0);
+                if now_depth > depth || now_index == layers_count - 1 {
+                    now_index_u8 = now_index as u8;
+                    break;
+                }
+            }
+        }
+        column.push(now_index_u8);
+    }
+
+    column
+}
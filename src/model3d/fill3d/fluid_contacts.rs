@@ -0,0 +1,88 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model3d::fill3d::filling_model_3d::column_mask;
+use crate::model3d::history3d::IntrusionSurfaces;
+use crate::types::{FillValue, FluidContact, FluidType};
+use crate::types::generation_params::Params3D;
+
+/// Applies fluid contacts to model and returns fluid mask (X->Y->Z like model).
+/// Contacts are flat, so they cut dipping and shifted borders of reservoir layer.
+/// Intrusion surfaces are used to skip intrusions when model mask wasn't created.
+pub fn apply_fluid_contacts<T: FillValue>(
+    params: &Params3D<T>,
+    borders: &[Vec<Vec<i32>>],
+    intrusion_surfaces: &IntrusionSurfaces,
+    model: &mut [Vec<Vec<T>>],
+    model_mask: &[Vec<Vec<u8>>],
+) -> Vec<Vec<Vec<u8>>> {
+    #[cfg(debug_assertions)]
+    trace!("Applying {} fluid contacts", params.fluid_contacts().len());
+
+    // Contacts for every layer sorted from the shallowest one
+    let mut layers_contacts: Vec<Vec<&FluidContact<T>>> = vec![Vec::new(); borders.len()];
+    for contact in params.fluid_contacts() {
+        if let Some(layer_contacts) = layers_contacts.get_mut(contact.layer()) {
+            layer_contacts.push(contact);
+        }
+    }
+    for layer_contacts in layers_contacts.iter_mut() {
+        layer_contacts.sort_by_key(|contact| contact.depth());
+    }
+
+    let y_size = borders[0].len();
+    let x_size = borders[0][0].len();
+    let model_ex = !model.is_empty();
+    let depth_size = if model_ex {
+        model[0][0].len()
+    } else {
+        model_mask[0][0].len()
+    };
+
+    let mut fluid_mask: Vec<Vec<Vec<u8>>> = Vec::with_capacity(x_size);
+
+    for x_cord in 0..x_size {
+        let mut now_x: Vec<Vec<u8>> = Vec::with_capacity(y_size);
+
+        for y_cord in 0..y_size {
+            let layers_column = if model_mask.is_empty() {
+                let mut column = column_mask(borders, x_cord, y_cord, depth_size);
+                for intrusion_surfaces in intrusion_surfaces.chunks(2) {
+                    let top = (intrusion_surfaces[0][y_cord][x_cord].max(0) as usize).min(depth_size);
+                    let base = (intrusion_surfaces[1][y_cord][x_cord].max(0) as usize).min(depth_size);
+                    for layer in column[top..base.max(top)].iter_mut() {
+                        *layer = borders.len() as u8;
+                    }
+                }
+                column
+            } else {
+                model_mask[x_cord][y_cord].clone()
+            };
+
+            let mut now_y = vec![0u8; depth_size];
+
+            for (depth, layer) in layers_column.iter().enumerate() {
+                // Intrusions have mask bigger than any layer and can't contain fluids
+                let contact = layers_contacts.get(*layer as usize).and_then(|layer_contacts| layer_contacts.iter()
+                    .find(|contact| (depth as i32) < contact.depth()));
+
+                if let Some(contact) = contact {
+                    now_y[depth] = contact.fluid().mask_value();
+                    if model_ex {
+                        let value = &mut model[x_cord][y_cord][depth];
+                        *value = contact.substitution().apply(*value);
+                    }
+                } else if layers_contacts.get(*layer as usize).is_some_and(|layer_contacts| !layer_contacts.is_empty()) {
+                    // Reservoir below its deepest contact is saturated with water, values are kept
+                    now_y[depth] = FluidType::Water.mask_value();
+                }
+            }
+
+            now_x.push(now_y);
+        }
+
+        fluid_mask.push(now_x);
+    }
+
+    fluid_mask
+}
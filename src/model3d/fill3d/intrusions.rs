@@ -0,0 +1,45 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model3d::history3d::IntrusionSurfaces;
+use crate::types::{FillValue, GeologicalEvent};
+use crate::types::generation_params::Params3D;
+
+/// Fills intrusions from history with their values, mask of intrusions is layers count.
+/// Intrusions are filled between their surfaces, which are moved by all events after intrusion
+pub fn apply_intrusions<T: FillValue>(
+    params: &Params3D<T>,
+    surfaces: &IntrusionSurfaces,
+    layers_count: usize,
+    model: &mut [Vec<Vec<T>>],
+    model_mask: &mut [Vec<Vec<u8>>],
+) {
+    let intrusions = params.events().iter().filter_map(|event| match event {
+        GeologicalEvent::Intrude(intrusion) => Some(intrusion),
+        _ => None,
+    });
+
+    for (intrusion, intrusion_surfaces) in intrusions.zip(surfaces.chunks(2)) {
+        #[cfg(debug_assertions)]
+        trace!("Applying intrusion: {:?}", intrusion);
+
+        let (top_surface, base_surface) = (&intrusion_surfaces[0], &intrusion_surfaces[1]);
+
+        for x_num in 0..params.x_axis().blocks_count() {
+            for y_num in 0..params.y_axis().blocks_count() {
+                let depth_size = model.get(x_num).map_or_else(|| model_mask[x_num][y_num].len(), |x_line| x_line[y_num].len());
+                let top = (top_surface[y_num][x_num].max(0) as usize).min(depth_size);
+                let base = (base_surface[y_num][x_num].max(0) as usize).min(depth_size);
+
+                for z in top..base {
+                    if let Some(x_line) = model.get_mut(x_num) {
+                        x_line[y_num][z] = intrusion.value();
+                    }
+                    if let Some(x_line) = model_mask.get_mut(x_num) {
+                        x_line[y_num][z] = layers_count as u8;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model3d::fill3d::filling_model_3d::column_mask;
+use crate::types::FillValue;
+use crate::types::generation_params::Params3D;
+
+/// Adds lateral trends of layers to already filled model
+pub fn apply_lateral_trends<T: FillValue>(
+    params: &Params3D<T>,
+    borders: &[Vec<Vec<i32>>],
+    model: &mut [Vec<Vec<T>>],
+    model_mask: &[Vec<Vec<u8>>],
+) -> Result<(), &'static str> {
+    #[cfg(debug_assertions)]
+    trace!("Applying lateral trends");
+
+    let mut layers_offsets: Vec<Option<Vec<Vec<f64>>>> = vec![None; borders.len()];
+    for (layer, trend) in params.lateral_trends().iter().enumerate().take(borders.len()) {
+        if let Some(trend) = trend {
+            layers_offsets[layer] = Some(trend.offsets(params.x_axis(), params.y_axis())?);
+        }
+    }
+
+    for (x_cord, model_x) in model.iter_mut().enumerate() {
+        for (y_cord, model_y) in model_x.iter_mut().enumerate() {
+            let layers_column = if model_mask.is_empty() {
+                column_mask(borders, x_cord, y_cord, model_y.len())
+            } else {
+                model_mask[x_cord][y_cord].clone()
+            };
+
+            for (value, layer) in model_y.iter_mut().zip(layers_column.iter()) {
+                if let Some(Some(offsets)) = layers_offsets.get(*layer as usize) {
+                    *value = T::from_f64(value.to_f64() + offsets[y_cord][x_cord]);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
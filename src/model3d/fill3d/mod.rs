@@ -1,24 +1,34 @@
-use rand::distributions::{Distribution, Uniform};
+use rand::distributions::Uniform;
 
 #[cfg(debug_assertions)]
 use log::trace;
 
+use crate::model3d::intervals3d::{ColumnsIntervals, intervals_depth};
+use crate::types::FillValue;
 use crate::types::generation_params::Params3D;
 
 pub mod filling_model_3d;
+pub mod presets_sequence;
+pub mod fluid_contacts;
+pub mod lateral_trends;
+pub mod intrusions;
 
 #[derive(Debug, Clone)]
-pub enum GenerationTypes {
-    GenerationRange(Uniform<i32>),
-    GenerationExact(i32),
+pub enum GenerationTypes<T: FillValue = i32> {
+    GenerationRange(Uniform<T>),
+    GenerationExact(T),
 }
 
-type FillFnReturnType = (Vec<Vec<Vec<i32>>>, Vec<Vec<Vec<u8>>>, Vec<Vec<i32>>);
+type FillFnReturnType<T> = (Vec<Vec<Vec<T>>>, Vec<Vec<Vec<u8>>>, Vec<Vec<T>>);
 
-pub fn fill(
-    params: &Params3D,
-    borders: &Vec<Vec<Vec<i32>>>
-) -> FillFnReturnType {
+/// Fills model from borders or from layer intervals if they are given, mask is always created
+/// for intervals
+pub fn fill<T: FillValue>(
+    params: &Params3D<T>,
+    borders: &Vec<Vec<Vec<i32>>>,
+    intervals: Option<&ColumnsIntervals>,
+    presets_sequence: &[usize]
+) -> FillFnReturnType<T> {
     #[cfg(debug_assertions)]
     trace!("Preparing for model fill");
 
@@ -26,28 +36,23 @@ pub fn fill(
     let deviation = params.layers_fill().values_deviation();
     let model_size = *params.layers_dist().get_layers_dist().last().unwrap_or(&0);
 
-    let mut fill_values_gen_type: Vec<GenerationTypes> = Vec::with_capacity(fill_values.len());
-    let mut export_fill_values: Vec<Vec<i32>> = Vec::with_capacity(fill_values.len());
+    let mut fill_values_gen_type: Vec<GenerationTypes<T>> = Vec::with_capacity(fill_values.len());
+    let mut export_fill_values: Vec<Vec<T>> = Vec::with_capacity(fill_values.len());
 
     for fill_value in &fill_values {
         match fill_value.len() {
             1 => fill_values_gen_type.push(
                 match deviation {
                     Some(dev_cof) => {
-                        let deviation = if dev_cof < 1.0 {
-                            (dev_cof * model_size as f32) as i32
-                        } else {
-                            dev_cof as i32
-                        };
-
-                        let first_dev = if deviation > fill_value[0] {
-                            0
-                        } else {
-                            fill_value[0] - deviation
-                        };
-
-                        export_fill_values.push(vec![first_dev, fill_value[0]+deviation]);
-                        GenerationTypes::GenerationRange(Uniform::from(first_dev..fill_value[0]+deviation+1))
+                        let deviation = T::deviation(dev_cof, model_size).to_f64();
+
+                        // Non-negative presets keep non-negative range, negative ones are not clamped
+                        let value = fill_value[0].to_f64();
+                        let first_dev = if value >= 0.0 { (value - deviation).max(0.0) } else { value - deviation };
+                        let (first_dev, last_dev) = (T::from_f64(first_dev), T::from_f64(value + deviation));
+
+                        export_fill_values.push(vec![first_dev, last_dev]);
+                        GenerationTypes::GenerationRange(Uniform::new_inclusive(first_dev, last_dev))
                     },
                     None => {
                         export_fill_values.push(vec![fill_value[0], fill_value[0]]);
@@ -58,7 +63,7 @@ pub fn fill(
             2 => {
                 export_fill_values.push(vec![fill_value[0], fill_value[1]]);
                 fill_values_gen_type.push(GenerationTypes::GenerationRange(
-                    Uniform::from(fill_value[0]..fill_value[1]+1)
+                    Uniform::new_inclusive(fill_value[0], fill_value[1])
                 ));
             },
             _ => unreachable!()
@@ -69,35 +74,22 @@ pub fn fill(
     trace!("Filling values for layers were recalculated, using deviation: {:?}", fill_values);
 
     // Reodering and adding values to Vec for making generation after easier
-    let mut new_fill_values: Vec<GenerationTypes> = Vec::with_capacity(borders.len());
-    let mut new_export_fill_values: Vec<Vec<i32>> = Vec::with_capacity(borders.len());
+    let mut new_fill_values: Vec<GenerationTypes<T>> = Vec::with_capacity(borders.len());
+    let mut new_export_fill_values: Vec<Vec<T>> = Vec::with_capacity(borders.len());
 
-    if params.layers_fill().is_preset_ordered() {
-        for i in 0..borders.len() {
-            new_fill_values.push(fill_values_gen_type[i % fill_values.len()].clone());
-            new_export_fill_values.push(export_fill_values[i % fill_values.len()].clone());
-        }
-    } else {
-        let mut rng = rand::thread_rng();
-
-        let possible_index = Uniform::from(0..fill_values_gen_type.len());
-
-        let mut last_index = possible_index.sample(&mut rng); 
-        let mut new_index = possible_index.sample(&mut rng); 
-        
-        if fill_values_gen_type.len() > 1 {
-            while new_fill_values.len() != borders.len() {
-                if last_index != new_index {
-                    new_fill_values.push(fill_values_gen_type[new_index].clone());
-                    new_export_fill_values.push(export_fill_values[new_index].clone());
-                    last_index = new_index;
-                }
-                new_index = possible_index.sample(&mut rng);
-            }
-        } else {
-            for _ in 0..borders.len() {
-                new_fill_values.push(fill_values_gen_type[0].clone());
-                new_export_fill_values.push(export_fill_values[0].clone());
+    for preset in presets_sequence {
+        new_fill_values.push(fill_values_gen_type[*preset].clone());
+        new_export_fill_values.push(export_fill_values[*preset].clone());
+    }
+
+    if let Some(property) = params.lithology_property() {
+        for (layer, layer_info) in params.layers_info().iter().enumerate().take(new_fill_values.len()) {
+            if let Some(lithology) = layer_info.lithology() {
+                let range = lithology.default_range(property);
+                let (min_value, max_value) = (T::from_f64(range[0]), T::from_f64(range[1]));
+
+                new_fill_values[layer] = GenerationTypes::GenerationRange(Uniform::new_inclusive(min_value, max_value));
+                new_export_fill_values[layer] = vec![min_value, max_value];
             }
         }
     }
@@ -105,7 +97,10 @@ pub fn fill(
     #[cfg(debug_assertions)]
     trace!("Filling values for model: {:?}", new_fill_values);
 
-    let (model, model_mask) = if params.mask_needed() && params.model_needed() {
+    let (model, model_mask) = if let Some(intervals) = intervals {
+        let depth_size = intervals_depth(intervals) as usize;
+        filling_model_3d::create_model_from_intervals(intervals, &new_fill_values, depth_size, params.model_needed())
+    } else if params.mask_needed() && params.model_needed() {
         filling_model_3d::create_full_model_with_mask(borders, &new_fill_values)
     } else if params.model_needed() {
         (filling_model_3d::create_full_model_without_mask(borders, &new_fill_values), Vec::new())
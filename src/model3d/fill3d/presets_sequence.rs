@@ -0,0 +1,92 @@
+use rand::Rng;
+use rand::distributions::{Distribution, Uniform, WeightedIndex};
+
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::types::{FillValue, LayersDist};
+use crate::types::generation_params::Params3D;
+
+/// Creates index of values preset for every layer. Ordered presets are cycled, unordered ones are
+/// taken from Markov chain if transition matrix exists or randomly without immediate repeats.
+pub fn create_presets_sequence<T: FillValue>(
+    params: &Params3D<T>,
+    layers_count: usize
+) -> Result<Vec<usize>, &'static str> {
+    let layers_fill = params.layers_fill();
+    let presets_count = layers_fill.values_preset().len();
+
+    if layers_fill.is_preset_ordered() {
+        return Ok((0..layers_count).map(|i| i % presets_count).collect())
+    }
+
+    let mut rng = rand::thread_rng();
+
+    if let Some(matrix) = layers_fill.transition_matrix() {
+        if matrix.len() != presets_count {
+            return Err("Transition matrix must contain row for every values preset")
+        }
+
+        // Presets can be changed after matrix was set, so its size is checked again
+        if matrix.iter().any(|row| row.len() != presets_count) {
+            return Err("Transition matrix must be square")
+        }
+
+        let mut rows: Vec<WeightedIndex<f32>> = Vec::with_capacity(matrix.len());
+        for row in matrix {
+            rows.push(WeightedIndex::new(row).map_err(|_| "Invalid row inside transition matrix")?);
+        }
+
+        // Layers are stored from the top, so chain starts from the deepest (oldest) one
+        let mut sequence = vec![0usize; layers_count];
+        let mut now_preset = rng.gen_range(0..presets_count);
+        for (num, layer_preset) in sequence.iter_mut().rev().enumerate() {
+            if num != 0 {
+                now_preset = rows[now_preset].sample(&mut rng);
+            }
+            *layer_preset = now_preset;
+        }
+
+        #[cfg(debug_assertions)]
+        trace!("Presets sequence from transition matrix: {:?}", sequence);
+
+        return Ok(sequence)
+    }
+
+    let mut sequence: Vec<usize> = Vec::with_capacity(layers_count);
+
+    if presets_count > 1 {
+        let possible_index = Uniform::from(0..presets_count);
+
+        let mut last_index = possible_index.sample(&mut rng);
+        let mut new_index = possible_index.sample(&mut rng);
+
+        while sequence.len() != layers_count {
+            if last_index != new_index {
+                sequence.push(new_index);
+                last_index = new_index;
+            }
+            new_index = possible_index.sample(&mut rng);
+        }
+    } else {
+        sequence = vec![0; layers_count];
+    }
+
+    Ok(sequence)
+}
+
+/// Generates layers distribution using thickness range of every layer's preset
+pub fn layers_dist_from_sequence(
+    thickness_ranges: &[[i32; 2]],
+    sequence: &[usize]
+) -> Result<LayersDist, &'static str> {
+    let mut rng = rand::thread_rng();
+
+    let mut layers_dist: Vec<i32> = Vec::with_capacity(sequence.len());
+    for preset in sequence {
+        let range = thickness_ranges.get(*preset).ok_or("Thickness ranges must contain range for every values preset")?;
+        layers_dist.push(rng.gen_range(range[0]..range[1]+1));
+    }
+
+    LayersDist::create_from_vec(layers_dist)
+}
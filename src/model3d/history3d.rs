@@ -0,0 +1,195 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model3d::fault_volumes::{FaultColumns, move_fault_columns};
+use crate::model3d::{borders3d, shifts3d};
+use crate::types::{FillValue, GeologicalEvent};
+use crate::types::generation_params::Params3D;
+
+/// Top and base surfaces (Y->X) of every intrusion in history, stored one after another. Cells of
+/// intrusion are between them, columns which are not crossed by intrusion have equal surfaces
+pub type IntrusionSurfaces = Vec<Vec<Vec<i32>>>;
+
+/// Applies geological history to borders. Layers are deposited from the deepest one, borders of
+/// existing layers and the surface they lie on are changed by events, borders of younger layers are
+/// kept above this surface when they are deposited. Intrusions are moved by later events like borders.
+/// Surfaces of faults are added to faults_columns if fault volumes are needed, surfaces of older
+/// faults are moved by every later event except erosion
+pub fn apply_history<T: FillValue>(
+    params: &Params3D<T>,
+    borders: &mut [Vec<Vec<i32>>],
+    max_depth: &mut i32,
+    faults_columns: &mut Vec<FaultColumns>,
+) -> Result<IntrusionSurfaces, &'static str> {
+    #[cfg(debug_assertions)]
+    trace!("Applying history of {} events", params.events().len());
+
+    // Index of the youngest existing layer, layers count means that only basement exists
+    let mut top = borders.len();
+    let mut intrusion_surfaces: IntrusionSurfaces = Vec::new();
+    let volumes_needed = params.fault_volumes_needed();
+
+    for event in params.events() {
+        // Surface of existing rocks is base of the next younger layer (or zero depth for full stack)
+        let existing = &mut borders[top.saturating_sub(1)..];
+
+        match event {
+            GeologicalEvent::Deposit { layers_count } => {
+                keep_above_surface(borders, top);
+                top = top.saturating_sub(*layers_count);
+            },
+            GeologicalEvent::Fold(fold) => {
+                deform(existing, &mut intrusion_surfaces, faults_columns, max_depth, |surfaces, depth| {
+                    shifts3d::add_fold_3d::add_fold(params, surfaces, fold, depth);
+                    Ok(())
+                })?;
+            },
+            GeologicalEvent::Shift(shift) => {
+                let shift_columns = shifts3d::add_shift_3d::shift_fault_columns(params, shift, *max_depth);
+                deform(existing, &mut intrusion_surfaces, faults_columns, max_depth, |surfaces, depth| {
+                    shifts3d::add_shift_3d::add_shift(params, surfaces, shift, depth);
+                    Ok(())
+                })?;
+                if volumes_needed {
+                    faults_columns.push(shift_columns);
+                }
+            },
+            GeologicalEvent::GrowthShift { shift, layers_count } => {
+                if *layers_count == 0 {
+                    return Err("Growth shift must deposit at least one layer")
+                }
+                let shift_columns = shifts3d::add_shift_3d::shift_fault_columns(params, shift, *max_depth);
+
+                let (step_force, remainder) = (shift.shift_force() / *layers_count as i32, shift.shift_force() % *layers_count as i32);
+                for step in 0..*layers_count as i32 {
+                    let mut step_shift = shift.clone();
+                    step_shift.set_shift_force(step_force + i32::from(step < remainder))?;
+
+                    deform(&mut borders[top.saturating_sub(1)..], &mut intrusion_surfaces, faults_columns, max_depth, |surfaces, depth| {
+                        shifts3d::add_shift_3d::add_shift(params, surfaces, &step_shift, depth);
+                        Ok(())
+                    })?;
+                    keep_above_surface(borders, top);
+                    top = top.saturating_sub(1);
+                }
+
+                if volumes_needed {
+                    faults_columns.push(shift_columns);
+                }
+            },
+            GeologicalEvent::Fault(fault) => {
+                deform(existing, &mut intrusion_surfaces, faults_columns, max_depth, |surfaces, depth| {
+                    shifts3d::add_fault_3d::add_fault(params, surfaces, fault, depth);
+                    Ok(())
+                })?;
+                if volumes_needed {
+                    faults_columns.push(shifts3d::add_fault_3d::fault_columns(params, fault));
+                }
+            },
+            GeologicalEvent::ListricFault(fault) => {
+                deform(existing, &mut intrusion_surfaces, faults_columns, max_depth, |surfaces, depth| {
+                    shifts3d::add_listric_fault_3d::add_listric_fault(params, surfaces, fault, depth);
+                    Ok(())
+                })?;
+                if volumes_needed {
+                    faults_columns.push(shifts3d::add_listric_fault_3d::listric_fault_columns(params, fault));
+                }
+            },
+            GeologicalEvent::RiftBlock(block) => {
+                deform(existing, &mut intrusion_surfaces, faults_columns, max_depth, |surfaces, depth| {
+                    shifts3d::add_rift_block_3d::add_rift_block(params, surfaces, block, depth)
+                })?;
+                if volumes_needed {
+                    faults_columns.extend(shifts3d::add_rift_block_3d::rift_block_fault_columns(params, block)?);
+                }
+            },
+            GeologicalEvent::StrikeSlipFault(fault) => {
+                deform(existing, &mut intrusion_surfaces, faults_columns, max_depth, |surfaces, _| {
+                    shifts3d::add_strike_slip_3d::add_strike_slip_fault(params, surfaces, fault);
+                    Ok(())
+                })?;
+                if volumes_needed {
+                    faults_columns.push(shifts3d::add_strike_slip_3d::strike_slip_fault_columns(params, fault));
+                }
+            },
+            GeologicalEvent::Erode(erosion_surface) => {
+                let surface = borders3d::erosion_surface::create_erosion_surface(params, erosion_surface)?;
+                // Existing borders and intrusions above erosion surface are truncated against it
+                for border in existing.iter_mut().chain(intrusion_surfaces.iter_mut()) {
+                    for (border_line, surface_line) in border.iter_mut().zip(&surface) {
+                        for (value, surface_value) in border_line.iter_mut().zip(surface_line) {
+                            *value = (*value).max(*surface_value);
+                        }
+                    }
+                }
+            },
+            GeologicalEvent::Intrude(intrusion) => {
+                // Intrusion replaces only existing rocks, so it can't go above their surface
+                let host_surface = if top == 0 {
+                    vec![vec![0; borders[0][0].len()]; borders[0].len()]
+                } else {
+                    borders[top - 1].clone()
+                };
+                let (mut top_surface, mut base_surface) = (host_surface.clone(), host_surface);
+
+                for (y_num, y) in params.y_axis().centers().iter().enumerate() {
+                    for (x_num, x) in params.x_axis().centers().iter().enumerate() {
+                        let host_depth = top_surface[y_num][x_num];
+                        if let Some((cells_top, cells_base)) = intrusion.column_cells(*x, *y) {
+                            if cells_base > host_depth {
+                                top_surface[y_num][x_num] = cells_top.max(host_depth);
+                                base_surface[y_num][x_num] = cells_base;
+                            }
+                        }
+                    }
+                }
+                intrusion_surfaces.push(top_surface);
+                intrusion_surfaces.push(base_surface);
+            },
+        }
+    }
+
+    // The rest of layers is deposited at the end of history
+    keep_above_surface(borders, top);
+
+    Ok(intrusion_surfaces)
+}
+
+/// Applies deformation to borders together with surfaces of older intrusions and faults. Surfaces
+/// get a copy of max depth, so only borders can change it
+pub fn deform<F>(
+    borders: &mut [Vec<Vec<i32>>],
+    intrusion_surfaces: &mut IntrusionSurfaces,
+    faults_columns: &mut [FaultColumns],
+    max_depth: &mut i32,
+    mut deformation: F,
+) -> Result<(), &'static str>
+where
+    F: FnMut(&mut [Vec<Vec<i32>>], &mut i32) -> Result<(), &'static str>
+{
+    move_fault_columns(faults_columns, *max_depth, &mut deformation)?;
+
+    if !intrusion_surfaces.is_empty() {
+        let mut surfaces_depth = *max_depth;
+        deformation(intrusion_surfaces, &mut surfaces_depth)?;
+    }
+
+    deformation(borders, max_depth)
+}
+
+// Younger borders can't go below surface of existing rocks
+fn keep_above_surface(borders: &mut [Vec<Vec<i32>>], top: usize) {
+    if top < 2 {
+        return
+    }
+    let (younger, existing) = borders.split_at_mut(top - 1);
+    let surface = &existing[0];
+
+    for border in younger.iter_mut() {
+        for (border_line, surface_line) in border.iter_mut().zip(surface) {
+            for (value, surface_value) in border_line.iter_mut().zip(surface_line) {
+                *value = (*value).min(*surface_value);
+            }
+        }
+    }
+}
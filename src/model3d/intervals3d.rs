@@ -0,0 +1,99 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::types::{FillValue, LayerInterval};
+use crate::types::generation_params::Params3D;
+use crate::types::shifts::Fault3D;
+
+/// Intervals of layers for every column (Y->X like borders), intervals are sorted from the top
+/// and cover column from zero depth without gaps
+pub type ColumnsIntervals = Vec<Vec<Vec<LayerInterval>>>;
+
+/// Converts single-valued borders to intervals, the deepest layer goes down to depth_size
+pub fn intervals_from_borders(borders: &[Vec<Vec<i32>>], depth_size: i32) -> ColumnsIntervals {
+    let layers_count = borders.len();
+
+    (0..borders[0].len()).map(|y_cord| {
+        (0..borders[0][0].len()).map(|x_cord| {
+            let mut column: Vec<LayerInterval> = Vec::with_capacity(layers_count);
+            let mut now_top = 0;
+
+            for (layer, border) in borders.iter().enumerate() {
+                let mut base = border[y_cord][x_cord];
+                if layer == layers_count - 1 {
+                    base = base.max(depth_size);
+                }
+                if base > now_top {
+                    column.push(LayerInterval::new(layer, now_top, base).unwrap());
+                    now_top = base;
+                }
+            }
+            column
+        }).collect()
+    }).collect()
+}
+
+/// Returns depth of the deepest interval
+pub fn intervals_depth(intervals: &ColumnsIntervals) -> i32 {
+    intervals.iter().flatten()
+        .filter_map(|column| column.last().map(|interval| interval.base()))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Moves hanging wall of fault along its plane without keeping layers order, so reverse faults
+/// repeat part of section in footwall (thrusts). Hanging wall is taken from source column above
+/// the plane, footwall stays below it
+pub fn add_thrust<T: FillValue>(params: &Params3D<T>, intervals: &mut ColumnsIntervals, fault: &Fault3D) {
+    #[cfg(debug_assertions)]
+    trace!("Starting applying thrust: {:?}", fault);
+
+    let x_ax = params.x_axis();
+    let y_ax = params.y_axis();
+    let source_intervals = intervals.clone();
+
+    for (y_num, y) in y_ax.centers().iter().enumerate() {
+        for (x_num, x) in x_ax.centers().iter().enumerate() {
+            // Vertical faults move whole column of hanging wall
+            let plane_depth = match fault.plane_depth(*x, *y) {
+                Some(depth) => depth.round() as i32,
+                None if fault.signed_distance(*x, *y, 0.0) > 0.0 => i32::MAX,
+                None => 0,
+            };
+
+            let profile_depth = fault.plane_depth(*x, *y).unwrap_or(fault.pos_z());
+            let throw = fault.throw() as f32 * fault.throw_factor(*x, *y, profile_depth);
+            let (shift_x, shift_y, shift_z) = fault.displacement(throw);
+            let source_column = &source_intervals[y_ax.nearest_block(y - shift_y)][x_ax.nearest_block(x - shift_x)];
+
+            let mut column: Vec<LayerInterval> = source_column.iter()
+                .filter_map(|interval| interval.moved(shift_z.round() as i32).clip(0, plane_depth))
+                .collect();
+            column.extend(source_intervals[y_num][x_num].iter().filter_map(|interval| interval.clip(plane_depth, i32::MAX)));
+
+            intervals[y_num][x_num] = close_gaps(column);
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    trace!("Thrust has been applied");
+}
+
+// Intervals are extended to cover gaps (and zero depth), neighbour parts of one layer are merged
+fn close_gaps(column: Vec<LayerInterval>) -> Vec<LayerInterval> {
+    let mut result: Vec<LayerInterval> = Vec::with_capacity(column.len());
+
+    for interval in column {
+        match result.last_mut() {
+            Some(last) if last.layer() == interval.layer() => {
+                *last = LayerInterval::new(last.layer(), last.top(), interval.base()).unwrap();
+            },
+            Some(last) => {
+                let top = last.base();
+                result.push(LayerInterval::new(interval.layer(), top, interval.base().max(top)).unwrap());
+            },
+            None => result.push(LayerInterval::new(interval.layer(), 0, interval.base()).unwrap()),
+        }
+    }
+    result
+}
@@ -1,6 +1,7 @@
 #[cfg(debug_assertions)]
 use log::trace;
 
+use crate::types::FillValue;
 use crate::types::generation_params::Params3D;
 
 pub mod borders3d;
@@ -8,52 +9,188 @@ pub mod shifts3d;
 pub mod fill3d;
 pub mod export;
 pub mod convert_data;
+pub mod fault_volumes;
+pub mod history3d;
+pub mod intervals3d;
+pub mod depth_slice;
+pub mod resample;
+pub mod upscale;
+pub mod query;
 
-pub fn generate_model(params: Params3D) -> Result<Model3D, &'static str> {
+pub fn generate_model<T: FillValue>(mut params: Params3D<T>) -> Result<Model3D<T>, &'static str> {
     #[cfg(debug_assertions)]
     trace!("Starting generating 3D model");
 
+    let presets_sequence = fill3d::presets_sequence::create_presets_sequence(
+        &params, params.layers_dist().get_layers_count())?;
+
+    if let Some(thickness_ranges) = params.layers_fill().thickness_ranges() {
+        let layers_dist = fill3d::presets_sequence::layers_dist_from_sequence(thickness_ranges, &presets_sequence)?;
+        params.set_layers_dist(layers_dist);
+    }
+
     let mut borders = borders3d::create_layers_borders_3d(&params)?;
     let mut max_depth = get_max_depth(&borders);
+    let mut faults_columns: Vec<fault_volumes::FaultColumns> = Vec::new();
+
+    // Generated faults replace population, so params of model create the same faults again
+    if let Some(population) = params.fault_population().clone() {
+        let faults = population.generate_faults(params.x_axis(), params.y_axis(), max_depth)?;
+        for fault in faults {
+            params.add_fault(fault);
+        }
+        params.set_fault_population(None);
+    }
 
+    let mut intrusion_surfaces = history3d::apply_history(&params, &mut borders, &mut max_depth, &mut faults_columns)?;
+    let volumes_needed = params.fault_volumes_needed();
+
+    // Shifts and faults from lists are applied after history to all layers, intrusions and older faults
     if !(params.shifts().is_empty()) {
         #[cfg(debug_assertions)]
         trace!("{} shifts found", params.shifts().len());
 
         for shift in params.shifts() {
-            shifts3d::add_shift_3d::add_shift(&params, &mut borders, shift, &mut max_depth);
+            let shift_columns = shifts3d::add_shift_3d::shift_fault_columns(&params, shift, max_depth);
+            history3d::deform(&mut borders, &mut intrusion_surfaces, &mut faults_columns, &mut max_depth, |surfaces, depth| {
+                shifts3d::add_shift_3d::add_shift(&params, surfaces, shift, depth);
+                Ok(())
+            })?;
+            if volumes_needed {
+                faults_columns.push(shift_columns);
+            }
         }
     }
 
-    let (model, model_mask, fill_values) = if params.model_needed() || params.mask_needed() {
-        fill3d::fill(&params, &borders)
+    for fault in params.faults() {
+        history3d::deform(&mut borders, &mut intrusion_surfaces, &mut faults_columns, &mut max_depth, |surfaces, depth| {
+            shifts3d::add_fault_3d::add_fault(&params, surfaces, fault, depth);
+            Ok(())
+        })?;
+        if volumes_needed {
+            faults_columns.push(shifts3d::add_fault_3d::fault_columns(&params, fault));
+        }
+    }
+
+    for fault in params.listric_faults() {
+        history3d::deform(&mut borders, &mut intrusion_surfaces, &mut faults_columns, &mut max_depth, |surfaces, depth| {
+            shifts3d::add_listric_fault_3d::add_listric_fault(&params, surfaces, fault, depth);
+            Ok(())
+        })?;
+        if volumes_needed {
+            faults_columns.push(shifts3d::add_listric_fault_3d::listric_fault_columns(&params, fault));
+        }
+    }
+
+    for block in params.rift_blocks() {
+        history3d::deform(&mut borders, &mut intrusion_surfaces, &mut faults_columns, &mut max_depth, |surfaces, depth| {
+            shifts3d::add_rift_block_3d::add_rift_block(&params, surfaces, block, depth)
+        })?;
+        if volumes_needed {
+            faults_columns.extend(shifts3d::add_rift_block_3d::rift_block_fault_columns(&params, block)?);
+        }
+    }
+
+    for fault in params.strike_slip_faults() {
+        history3d::deform(&mut borders, &mut intrusion_surfaces, &mut faults_columns, &mut max_depth, |surfaces, _| {
+            shifts3d::add_strike_slip_3d::add_strike_slip_fault(&params, surfaces, fault);
+            Ok(())
+        })?;
+        if volumes_needed {
+            faults_columns.push(shifts3d::add_strike_slip_3d::strike_slip_fault_columns(&params, fault));
+        }
+    }
+
+    // Thrusts can repeat layers in a column, so they are applied to layer intervals instead of borders
+    let intervals = if params.thrusts().is_empty() {
+        Vec::new()
+    } else {
+        let mut intervals = intervals3d::intervals_from_borders(&borders, max_depth);
+        for fault in params.thrusts() {
+            // Surfaces can't be repeated, so intrusions and older faults are moved by thrust like by planar fault
+            history3d::deform(&mut [], &mut intrusion_surfaces, &mut faults_columns, &mut max_depth.clone(), |surfaces, depth| {
+                shifts3d::add_fault_3d::add_fault(&params, surfaces, fault, depth);
+                Ok(())
+            })?;
+            if volumes_needed {
+                faults_columns.push(shifts3d::add_fault_3d::fault_columns(&params, fault));
+            }
+            intervals3d::add_thrust(&params, &mut intervals, fault);
+        }
+        max_depth = intervals3d::intervals_depth(&intervals);
+        intervals
+    };
+    let intervals_ex = !intervals.is_empty();
+
+    let (mut model, mut model_mask, fill_values) = if params.model_needed() || params.mask_needed() {
+        fill3d::fill(&params, &borders, intervals_ex.then_some(&intervals), &presets_sequence)
     } else {
         (Vec::new(), Vec::new(), Vec::new())
     };
 
-    let final_model = Model3D::new(model, model_mask, borders, fill_values, max_depth, params);
+    if params.lateral_trends().iter().any(|trend| trend.is_some()) && params.model_needed() {
+        fill3d::lateral_trends::apply_lateral_trends(&params, &borders, &mut model, &model_mask)?;
+    }
+
+    if !intrusion_surfaces.is_empty() {
+        fill3d::intrusions::apply_intrusions(&params, &intrusion_surfaces, borders.len(), &mut model, &mut model_mask);
+    }
+
+    let fluid_mask = if !params.fluid_contacts().is_empty() && (params.model_needed() || params.mask_needed()) {
+        fill3d::fluid_contacts::apply_fluid_contacts(&params, &borders, &intrusion_surfaces, &mut model, &model_mask)
+    } else {
+        Vec::new()
+    };
+
+    // Mask is always created for intervals, because borders can't describe layers of column
+    if intervals_ex && !params.mask_needed() {
+        model_mask = Vec::new();
+    }
+
+    let fault_volumes = if params.fault_volumes_needed() {
+        Some(fault_volumes::create_fault_volumes(&faults_columns,
+            params.x_axis().blocks_count(), params.y_axis().blocks_count(), max_depth as usize)?)
+    } else {
+        None
+    };
+
+    let mut final_model = Model3D::new(model, model_mask, borders, fill_values, max_depth, params);
+    final_model.set_fluid_mask(fluid_mask);
+    final_model.set_intervals(intervals);
+
+    if let Some((fault_mask, fault_distance, fault_blocks)) = fault_volumes {
+        final_model.set_fault_mask(fault_mask);
+        final_model.set_fault_distance(fault_distance);
+        final_model.set_fault_blocks(fault_blocks);
+    }
 
     Ok(final_model)
 }
 
 #[derive(Debug, Clone)]
-pub struct Model3D {
-    model: Vec<Vec<Vec<i32>>>,
+pub struct Model3D<T = i32> {
+    model: Vec<Vec<Vec<T>>>,
     model_mask: Vec<Vec<Vec<u8>>>,
     borders: Vec<Vec<Vec<i32>>>,
-    layers_filling_values: Vec<Vec<i32>>,
+    layers_filling_values: Vec<Vec<T>>,
     max_depth: i32,
-    params: Params3D,
+    params: Params3D<T>,
+    // Optional outputs, empty if they were not generated
+    fluid_mask: Vec<Vec<Vec<u8>>>,
+    fault_mask: Vec<Vec<Vec<u16>>>,
+    fault_distance: Vec<Vec<Vec<f32>>>,
+    fault_blocks: Vec<Vec<Vec<u16>>>,
+    intervals: intervals3d::ColumnsIntervals,
 }
 
-impl Model3D {
+impl<T: FillValue> Model3D<T> {
     pub fn new(
-        model: Vec<Vec<Vec<i32>>>,
+        model: Vec<Vec<Vec<T>>>,
         model_mask: Vec<Vec<Vec<u8>>>,
         borders: Vec<Vec<Vec<i32>>>,
-        layers_filling_values: Vec<Vec<i32>>,
+        layers_filling_values: Vec<Vec<T>>,
         max_depth: i32,
-        params: Params3D) -> Model3D {
+        params: Params3D<T>) -> Model3D<T> {
         Model3D {
             model,
             model_mask,
@@ -61,12 +198,17 @@ impl Model3D {
             layers_filling_values,
             max_depth,
             params,
+            fluid_mask: Vec::new(),
+            fault_mask: Vec::new(),
+            fault_distance: Vec::new(),
+            fault_blocks: Vec::new(),
+            intervals: Vec::new(),
         }
     } 
 }
 
-impl Model3D {
-    pub fn model(&self) -> &Vec<Vec<Vec<i32>>> {
+impl<T: FillValue> Model3D<T> {
+    pub fn model(&self) -> &Vec<Vec<Vec<T>>> {
         &self.model
     }
 
@@ -74,11 +216,12 @@ impl Model3D {
         &self.model_mask
     }
     
+    /// Returns borders (Z->Y->X), for model with thrusts they are taken before thrusts, see `intervals`
     pub fn borders(&self) -> &Vec<Vec<Vec<i32>>> {
         &self.borders
     }
 
-    pub fn layers_filling_values(&self) -> &Vec<Vec<i32>> {
+    pub fn layers_filling_values(&self) -> &Vec<Vec<T>> {
         &self.layers_filling_values
     }
 
@@ -86,9 +229,65 @@ impl Model3D {
         self.max_depth
     }
 
-    pub fn params(&self) -> &Params3D {
+    pub fn params(&self) -> &Params3D<T> {
         &self.params
     }
+
+    pub fn set_fluid_mask(&mut self, fluid_mask: Vec<Vec<Vec<u8>>>) {
+        self.fluid_mask = fluid_mask
+    }
+
+    /// Returns fluid mask (X->Y->Z), 0 means no fluid, see `FluidType::mask_value`
+    pub fn fluid_mask(&self) -> &Vec<Vec<Vec<u8>>> {
+        &self.fluid_mask
+    }
+
+    pub fn set_fault_mask(&mut self, fault_mask: Vec<Vec<Vec<u16>>>) {
+        self.fault_mask = fault_mask
+    }
+
+    /// Returns fault mask (X->Y->Z), 0 means no fault, otherwise number of fault + 1
+    /// (faults of history events first, then shifts, planar faults, listric faults, both faults of every rift block,
+    /// strike-slip faults and thrusts)
+    pub fn fault_mask(&self) -> &Vec<Vec<Vec<u16>>> {
+        &self.fault_mask
+    }
+
+    pub fn set_fault_distance(&mut self, fault_distance: Vec<Vec<Vec<f32>>>) {
+        self.fault_distance = fault_distance
+    }
+
+    /// Returns distance in blocks to the nearest fault (X->Y->Z), it's empty if no fault crosses the model
+    pub fn fault_distance(&self) -> &Vec<Vec<Vec<f32>>> {
+        &self.fault_distance
+    }
+
+    pub fn set_fault_blocks(&mut self, fault_blocks: Vec<Vec<Vec<u16>>>) {
+        self.fault_blocks = fault_blocks
+    }
+
+    /// Returns id of fault block for every cell (X->Y->Z), cells on the same side of every fault share id
+    pub fn fault_blocks(&self) -> &Vec<Vec<Vec<u16>>> {
+        &self.fault_blocks
+    }
+
+    pub fn set_intervals(&mut self, intervals: intervals3d::ColumnsIntervals) {
+        self.intervals = intervals
+    }
+
+    /// Returns layer intervals for every column (Y->X), they are created only if model has thrusts,
+    /// borders of such model don't contain thrusts
+    pub fn intervals(&self) -> &intervals3d::ColumnsIntervals {
+        &self.intervals
+    }
+
+    // Operations which read borders can't be used for models with thrusts
+    fn check_borders_describe_model(&self) -> Result<(), &'static str> {
+        if !self.intervals.is_empty() {
+            return Err("Borders of model with thrusts don't contain thrusts, use intervals instead")
+        }
+        Ok(())
+    }
 }
 
 fn get_max_depth(borders: &[Vec<Vec<i32>>]) -> i32 {
@@ -0,0 +1,103 @@
+use crate::model3d::Model3D;
+use crate::types::{FillValue, Interpolation};
+
+// Blocks along axis with weights
+type AxisNeighbours = Vec<(usize, f32)>;
+
+impl<T: FillValue> Model3D<T> {
+    /// Returns value at point, x and y are measured in axis units, z in depth samples (sample
+    /// n covers depths from n to n + 1). Linear interpolation is trilinear between block centers
+    pub fn value_at(&self, x: f32, y: f32, z: f32, interpolation: Interpolation) -> Result<T, &'static str> {
+        if self.model.is_empty() {
+            return Err("Model doesn't exists in object")
+        }
+
+        let [x_neighbours, y_neighbours, z_neighbours] = self.point_neighbours(x, y, z, interpolation)?;
+
+        let mut value = 0.0;
+        for (x_num, x_weight) in &x_neighbours {
+            for (y_num, y_weight) in &y_neighbours {
+                for (z_num, z_weight) in &z_neighbours {
+                    value += self.model[*x_num][*y_num][*z_num].to_f64() * (x_weight * y_weight * z_weight) as f64;
+                }
+            }
+        }
+        Ok(T::from_f64(value))
+    }
+
+    /// Returns layer at point (the nearest block), it's taken from mask if it exists, otherwise
+    /// from borders (error for models with thrusts)
+    pub fn layer_at(&self, x: f32, y: f32, z: f32) -> Result<u8, &'static str> {
+        let [x_neighbours, y_neighbours, z_neighbours] = self.point_neighbours(x, y, z, Interpolation::Nearest)?;
+        let (x_num, y_num, z_num) = (x_neighbours[0].0, y_neighbours[0].0, z_neighbours[0].0);
+
+        if !self.model_mask.is_empty() {
+            return Ok(self.model_mask[x_num][y_num][z_num])
+        }
+        self.check_borders_describe_model()?;
+
+        let layer = self.borders.iter()
+            .position(|border| border[y_num][x_num] > z_num as i32)
+            .unwrap_or(self.borders.len().saturating_sub(1));
+        Ok(layer as u8)
+    }
+
+    /// Returns depth of layer's base at point, bilinear interpolation between block centers.
+    /// Layers of models with thrusts can have several bases, so error is returned for them
+    pub fn horizon_depth_at(&self, layer: usize, x: f32, y: f32) -> Result<f32, &'static str> {
+        self.check_borders_describe_model()?;
+        let border = self.borders.get(layer).ok_or("Layer out of bounds")?;
+
+        if self.params.x_axis().find_element_smaller(x).is_none() || self.params.y_axis().find_element_smaller(y).is_none() {
+            return Err("Point is outside model")
+        }
+
+        let x_neighbours = linear_weights(self.params.x_axis().linear_neighbours(x));
+        let y_neighbours = linear_weights(self.params.y_axis().linear_neighbours(y));
+
+        let mut depth = 0.0;
+        for (x_num, x_weight) in &x_neighbours {
+            for (y_num, y_weight) in &y_neighbours {
+                depth += border[*y_num][*x_num] as f32 * x_weight * y_weight;
+            }
+        }
+        Ok(depth)
+    }
+
+    /// Returns values at points (x, y, z), e.g. along well path
+    pub fn values_at(&self, points: &[[f32; 3]], interpolation: Interpolation) -> Result<Vec<T>, &'static str> {
+        points.iter().map(|point| self.value_at(point[0], point[1], point[2], interpolation)).collect()
+    }
+
+    /// Returns layers at points (x, y, z)
+    pub fn layers_at(&self, points: &[[f32; 3]]) -> Result<Vec<u8>, &'static str> {
+        points.iter().map(|point| self.layer_at(point[0], point[1], point[2])).collect()
+    }
+
+    /// Returns depths of layer's base at points (x, y)
+    pub fn horizon_depths_at(&self, layer: usize, points: &[[f32; 2]]) -> Result<Vec<f32>, &'static str> {
+        points.iter().map(|point| self.horizon_depth_at(layer, point[0], point[1])).collect()
+    }
+
+    fn point_neighbours(&self, x: f32, y: f32, z: f32, interpolation: Interpolation) -> Result<[AxisNeighbours; 3], &'static str> {
+        let depth_axis = self.depth_axis()?;
+        let axes = [self.params.x_axis(), self.params.y_axis(), &depth_axis];
+
+        let mut neighbours: [AxisNeighbours; 3] = Default::default();
+        for (axis_num, (axis, coord)) in axes.iter().zip([x, y, z]).enumerate() {
+            if axis.find_element_smaller(coord).is_none() {
+                return Err("Point is outside model")
+            }
+
+            neighbours[axis_num] = match interpolation {
+                Interpolation::Nearest => vec![(axis.nearest_block(coord), 1.0)],
+                Interpolation::Linear => linear_weights(axis.linear_neighbours(coord)),
+            };
+        }
+        Ok(neighbours)
+    }
+}
+
+fn linear_weights((first, second, part): (usize, usize, f32)) -> AxisNeighbours {
+    vec![(first, 1.0 - part), (second, part)]
+}
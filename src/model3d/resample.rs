@@ -0,0 +1,229 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model3d::Model3D;
+use crate::model3d::fault_volumes;
+use crate::model3d::intervals3d::ColumnsIntervals;
+use crate::types::{Axis, AxisRange, FillValue, ResampleMethod};
+
+// Source blocks with weights for every block of new axis
+type AxisWeights = Vec<Vec<(usize, f64)>>;
+
+impl<T: FillValue> Model3D<T> {
+    /// Cuts part of model, borders are measured from the top of new model and clamped to it,
+    /// axes of params are replaced by cropped ones. Layer intervals of models with thrusts are cut
+    /// the same way, so borders of cropped model still don't contain thrusts
+    pub fn crop(&self, x_range: AxisRange, y_range: AxisRange, z_range: AxisRange) -> Result<Model3D<T>, &'static str> {
+        let (x_start, x_end) = range_nums(&x_range, self.params.x_axis())?;
+        let (y_start, y_end) = range_nums(&y_range, self.params.y_axis())?;
+        let (z_start, z_end) = range_nums(&z_range, &self.depth_axis()?)?;
+
+        #[cfg(debug_assertions)]
+        trace!("Cropping model: x {x_start}..{x_end}, y {y_start}..{y_end}, z {z_start}..{z_end}");
+
+        let depth_size = (z_end - z_start) as i32;
+
+        let borders: Vec<Vec<Vec<i32>>> = self.borders.iter().map(|layer| {
+            layer[y_start..y_end].iter().map(|y_line| {
+                y_line[x_start..x_end].iter().map(|border| (border - z_start as i32).clamp(0, depth_size)).collect()
+            }).collect()
+        }).collect();
+
+        let ranges = [(x_start, x_end), (y_start, y_end), (z_start, z_end)];
+
+        let intervals: ColumnsIntervals = if self.intervals.is_empty() {
+            Vec::new()
+        } else {
+            self.intervals[y_start..y_end].iter().map(|y_line| {
+                y_line[x_start..x_end].iter().map(|column| {
+                    column.iter()
+                        .filter_map(|interval| interval.clip(z_start as i32, z_end as i32))
+                        .map(|interval| interval.moved(-(z_start as i32)))
+                        .collect()
+                }).collect()
+            }).collect()
+        };
+
+        let mut params = self.params.clone();
+        params.set_x_axis(Axis::create_from_edges(self.params.x_axis().axis()[x_start..=x_end].to_vec()).unwrap());
+        params.set_y_axis(Axis::create_from_edges(self.params.y_axis().axis()[y_start..=y_end].to_vec()).unwrap());
+
+        let mut model = Model3D::new(
+            crop_volume(&self.model, ranges),
+            crop_volume(&self.model_mask, ranges),
+            borders,
+            self.layers_filling_values.clone(),
+            depth_size,
+            params,
+        );
+        model.set_fluid_mask(crop_volume(&self.fluid_mask, ranges));
+        model.set_fault_mask(crop_volume(&self.fault_mask, ranges));
+        model.set_fault_distance(crop_volume(&self.fault_distance, ranges));
+        model.set_fault_blocks(crop_volume(&self.fault_blocks, ranges));
+        model.set_intervals(intervals);
+
+        Ok(model)
+    }
+
+    /// Resamples model to new axes, z axis is measured in samples of current model. Values are
+    /// resampled with method, borders are interpolated laterally with the same method, masks and
+    /// fault volumes always use nearest block, distance to faults is recalculated in new blocks.
+    /// Max depth of new model is size of z axis, but generation params (e.g. layers_dist) are kept
+    /// as they were, so their depths are still measured in samples of source model.
+    /// Models with thrusts can't be resampled, because their borders don't contain thrusts
+    pub fn resample(&self, x_axis: Axis, y_axis: Axis, z_axis: Axis, method: ResampleMethod) -> Result<Model3D<T>, &'static str> {
+        self.check_borders_describe_model()?;
+
+        #[cfg(debug_assertions)]
+        trace!("Resampling model with {:?} method", method);
+
+        let depth_axis = self.depth_axis()?;
+
+        let x_weights = axis_weights(self.params.x_axis(), &x_axis, method);
+        let y_weights = axis_weights(self.params.y_axis(), &y_axis, method);
+        let z_weights = axis_weights(&depth_axis, &z_axis, method);
+
+        let x_nearest = axis_weights(self.params.x_axis(), &x_axis, ResampleMethod::Nearest);
+        let y_nearest = axis_weights(self.params.y_axis(), &y_axis, ResampleMethod::Nearest);
+        let z_nearest = axis_weights(&depth_axis, &z_axis, ResampleMethod::Nearest);
+
+        // Border is moved to the first new sample which center is below it, so borders match mask
+        let borders: Vec<Vec<Vec<i32>>> = self.borders.iter().map(|layer| {
+            y_weights.iter().map(|y_line| {
+                x_weights.iter().map(|x_line| {
+                    let mut border = 0.0;
+                    for (y_num, y_weight) in y_line {
+                        for (x_num, x_weight) in x_line {
+                            border += layer[*y_num][*x_num] as f64 * y_weight * x_weight;
+                        }
+                    }
+                    let border = border.round() as i32;
+                    z_axis.centers().partition_point(|center| (center.floor() as i32) < border) as i32
+                }).collect()
+            }).collect()
+        }).collect();
+
+        let model: Vec<Vec<Vec<T>>> = if self.model.is_empty() {
+            Vec::new()
+        } else {
+            x_weights.iter().map(|x_line| {
+                y_weights.iter().map(|y_line| {
+                    z_weights.iter().map(|z_line| {
+                        let mut value = 0.0;
+                        for (x_num, x_weight) in x_line {
+                            for (y_num, y_weight) in y_line {
+                                for (z_num, z_weight) in z_line {
+                                    value += self.model[*x_num][*y_num][*z_num].to_f64() * x_weight * y_weight * z_weight;
+                                }
+                            }
+                        }
+                        T::from_f64(value)
+                    }).collect()
+                }).collect()
+            }).collect()
+        };
+
+        let nearest = [&x_nearest, &y_nearest, &z_nearest];
+
+        let mut params = self.params.clone();
+        params.set_x_axis(x_axis);
+        params.set_y_axis(y_axis);
+
+        let mut resampled = Model3D::new(
+            model,
+            resample_nearest(&self.model_mask, nearest),
+            borders,
+            self.layers_filling_values.clone(),
+            z_axis.blocks_count() as i32,
+            params,
+        );
+        resampled.set_fluid_mask(resample_nearest(&self.fluid_mask, nearest));
+        let fault_mask = resample_nearest(&self.fault_mask, nearest);
+        resampled.set_fault_distance(fault_volumes::distance_to_faults(&fault_mask));
+        resampled.set_fault_mask(fault_mask);
+        resampled.set_fault_blocks(resample_nearest(&self.fault_blocks, nearest));
+
+        Ok(resampled)
+    }
+
+    /// Axis of depth samples, edges are 0..max_depth
+    pub(crate) fn depth_axis(&self) -> Result<Axis, &'static str> {
+        Axis::create_from_edges((0..=self.max_depth).map(|edge| edge as f32).collect())
+            .map_err(|_| "Model has no depth")
+    }
+}
+
+fn range_nums(range: &AxisRange, axis: &Axis) -> Result<(usize, usize), &'static str> {
+    let (start, end) = match *range {
+        AxisRange::Index { start, end } => (start, end.min(axis.blocks_count())),
+        AxisRange::World { start, end } => {
+            if start >= end {
+                return Err("Start of range must be smaller than its end")
+            }
+            let edges = axis.axis();
+            (edges.partition_point(|edge| *edge <= start).saturating_sub(1),
+                edges.partition_point(|edge| *edge < end).min(axis.blocks_count()))
+        },
+    };
+
+    if start >= end {
+        return Err("Range doesn't contain blocks of model")
+    }
+    Ok((start, end))
+}
+
+fn crop_volume<V: Copy>(volume: &[Vec<Vec<V>>], ranges: [(usize, usize); 3]) -> Vec<Vec<Vec<V>>> {
+    if volume.is_empty() {
+        return Vec::new()
+    }
+
+    let [(x_start, x_end), (y_start, y_end), (z_start, z_end)] = ranges;
+
+    volume[x_start..x_end].iter().map(|x_line| {
+        x_line[y_start..y_end].iter().map(|column| column[z_start..z_end].to_vec()).collect()
+    }).collect()
+}
+
+fn axis_weights(source: &Axis, target: &Axis, method: ResampleMethod) -> AxisWeights {
+    let edges = target.axis();
+
+    target.centers().iter().enumerate().map(|(num, center)| {
+        match method {
+            ResampleMethod::Nearest => vec![(source.nearest_block(*center), 1.0)],
+            ResampleMethod::Linear => {
+                let (first, second, part) = source.linear_neighbours(*center);
+                vec![(first, 1.0 - part as f64), (second, part as f64)]
+            },
+            ResampleMethod::Average => {
+                let is_last = num == edges.len() - 2;
+                let inside: Vec<usize> = source.centers().iter().enumerate()
+                    .filter(|(_, source_center)| {
+                        **source_center >= edges[num] && (**source_center < edges[num + 1] || is_last && **source_center <= edges[num + 1])
+                    })
+                    .map(|(source_num, _)| source_num)
+                    .collect();
+
+                if inside.is_empty() {
+                    vec![(source.nearest_block(*center), 1.0)]
+                } else {
+                    let weight = 1.0 / inside.len() as f64;
+                    inside.into_iter().map(|source_num| (source_num, weight)).collect()
+                }
+            },
+        }
+    }).collect()
+}
+
+fn resample_nearest<V: Copy>(volume: &[Vec<Vec<V>>], nearest: [&AxisWeights; 3]) -> Vec<Vec<Vec<V>>> {
+    if volume.is_empty() {
+        return Vec::new()
+    }
+
+    let [x_nearest, y_nearest, z_nearest] = nearest;
+
+    x_nearest.iter().map(|x_line| {
+        y_nearest.iter().map(|y_line| {
+            z_nearest.iter().map(|z_line| volume[x_line[0].0][y_line[0].0][z_line[0].0]).collect()
+        }).collect()
+    }).collect()
+}
@@ -0,0 +1,68 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model3d::fault_volumes::FaultColumns;
+use crate::types::FillValue;
+use crate::types::generation_params::Params3D;
+use crate::types::shifts::Fault3D;
+
+/// Moves hanging wall of planar fault along the fault plane. Every border takes hanging wall value if
+/// it is above the plane, footwall value if it is below, otherwise border ends at the fault plane.
+/// Throw is scaled by fault's displacement profile.
+pub fn add_fault<T: FillValue>(params: &Params3D<T>, borders: &mut [Vec<Vec<i32>>], fault: &Fault3D, max_depth: &mut i32) {
+    #[cfg(debug_assertions)]
+    trace!("Starting applying fault: {:?}", fault);
+
+    let x_ax = params.x_axis();
+    let y_ax = params.y_axis();
+    let x_centers = x_ax.centers();
+    let y_centers = y_ax.centers();
+
+    for border in borders.iter_mut() {
+        let source_border = border.clone();
+
+        for (y_num, y) in y_centers.iter().enumerate() {
+            for (x_num, x) in x_centers.iter().enumerate() {
+                let footwall_value = source_border[y_num][x_num];
+
+                // Throw is taken at the place of the border, so it dies out towards tip line
+                let throw = fault.throw() as f32 * fault.throw_factor(*x, *y, footwall_value as f32);
+                let (shift_x, shift_y, shift_z) = fault.displacement(throw);
+
+                let source_x_num = x_ax.nearest_block(x - shift_x);
+                let source_y_num = y_ax.nearest_block(y - shift_y);
+                let hanging_wall_value = source_border[source_y_num][source_x_num] + shift_z.round() as i32;
+
+                let new_value = if fault.signed_distance(*x, *y, hanging_wall_value as f32) > 0.0 {
+                    hanging_wall_value
+                } else if fault.signed_distance(*x, *y, footwall_value as f32) <= 0.0 {
+                    footwall_value
+                } else {
+                    fault.plane_depth(*x, *y).unwrap_or(footwall_value as f32).round() as i32
+                };
+
+                let new_value = new_value.max(0);
+                if new_value > *max_depth {
+                    *max_depth = new_value
+                }
+                border[y_num][x_num] = new_value;
+            }
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    trace!("Fault has been applied");
+}
+
+/// Returns surface of fault for fault volumes, hanging wall is always above the plane
+pub fn fault_columns<T: FillValue>(params: &Params3D<T>, fault: &Fault3D) -> FaultColumns {
+    params.y_axis().centers().iter().map(|y| {
+        params.x_axis().centers().iter().map(|x| {
+            match fault.plane_depth(*x, *y) {
+                Some(depth) => (depth, true),
+                None if fault.signed_distance(*x, *y, 0.0) > 0.0 => (f32::INFINITY, true),
+                None => (f32::NEG_INFINITY, true),
+            }
+        }).collect()
+    }).collect()
+}
@@ -0,0 +1,25 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::types::{FillValue, Fold3D};
+use crate::types::generation_params::Params3D;
+
+/// Moves every border vertically by fold's offset, borders can't go above zero depth
+pub fn add_fold<T: FillValue>(params: &Params3D<T>, borders: &mut [Vec<Vec<i32>>], fold: &Fold3D, max_depth: &mut i32) {
+    #[cfg(debug_assertions)]
+    trace!("Starting applying fold: {:?}", fold);
+
+    for (y_num, y) in params.y_axis().centers().iter().enumerate() {
+        for (x_num, x) in params.x_axis().centers().iter().enumerate() {
+            let offset = fold.offset(*x, *y).round() as i32;
+
+            for border in borders.iter_mut() {
+                let now_border = &mut border[y_num][x_num];
+                *now_border = (*now_border + offset).max(0);
+                if *now_border > *max_depth {
+                    *max_depth = *now_border
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model3d::fault_volumes::FaultColumns;
+use crate::types::FillValue;
+use crate::types::generation_params::Params3D;
+use crate::types::shifts::{ListricFault3D, FaultSense};
+use crate::types::shifts::listric_fault3d::TracePosition;
+
+/// Moves hanging wall of listric fault. Hanging wall is shifted horizontally by heave along local dip
+/// direction of the trace, vertical movement follows the curved fault if rollover is enabled.
+pub fn add_listric_fault<T: FillValue>(
+    params: &Params3D<T>,
+    borders: &mut [Vec<Vec<i32>>],
+    fault: &ListricFault3D,
+    max_depth: &mut i32
+) {
+    #[cfg(debug_assertions)]
+    trace!("Starting applying listric fault: {:?}", fault);
+
+    let x_ax = params.x_axis();
+    let y_ax = params.y_axis();
+    let trace_points = fault.trace_points();
+
+    // Position of every column relative to trace is the same for all borders
+    let columns_position: Vec<Vec<TracePosition>> = y_ax.centers().iter().map(|y| {
+        x_ax.centers().iter().map(|x| ListricFault3D::trace_position(&trace_points, *x, *y)).collect()
+    }).collect();
+
+    for border in borders.iter_mut() {
+        let source_border = border.clone();
+
+        for (y_num, y) in y_ax.centers().iter().enumerate() {
+            for (x_num, x) in x_ax.centers().iter().enumerate() {
+                let (distance, along_trace, (dip_x, dip_y)) = columns_position[y_num][x_num];
+                let footwall_value = source_border[y_num][x_num];
+                let fault_depth = fault.fault_depth(distance);
+
+                let throw = fault.throw() as f32 *
+                    fault.displacement_profile().factor(along_trace, footwall_value as f32 - fault.trace_depth());
+                let heave = match fault.fault_sense() {
+                    FaultSense::Normal => fault.heave(throw),
+                    FaultSense::Reverse => -fault.heave(throw),
+                };
+
+                let source_distance = distance - heave;
+                let shift_z = if fault.rollover() {
+                    fault_depth - fault.fault_depth(source_distance)
+                } else if heave >= 0.0 {
+                    throw
+                } else {
+                    -throw
+                };
+
+                let source_x_num = x_ax.nearest_block(x - heave * dip_x);
+                let source_y_num = y_ax.nearest_block(y - heave * dip_y);
+                let source_value = source_border[source_y_num][source_x_num];
+                let hanging_wall_value = (source_value as f32 + shift_z).round() as i32;
+
+                let new_value = if (source_value as f32) < fault.fault_depth(source_distance)
+                    && (hanging_wall_value as f32) < fault_depth {
+                    hanging_wall_value
+                } else if footwall_value as f32 >= fault_depth {
+                    footwall_value
+                } else {
+                    fault_depth.round() as i32
+                };
+
+                let new_value = new_value.max(0);
+                if new_value > *max_depth {
+                    *max_depth = new_value
+                }
+                border[y_num][x_num] = new_value;
+            }
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    trace!("Listric fault has been applied");
+}
+
+/// Returns surface of listric fault for fault volumes, hanging wall is above the fault
+pub fn listric_fault_columns<T: FillValue>(params: &Params3D<T>, fault: &ListricFault3D) -> FaultColumns {
+    let trace_points = fault.trace_points();
+
+    params.y_axis().centers().iter().map(|y| {
+        params.x_axis().centers().iter().map(|x| {
+            let (distance, _, _) = ListricFault3D::trace_position(&trace_points, *x, *y);
+            (fault.fault_depth(distance), true)
+        }).collect()
+    }).collect()
+}
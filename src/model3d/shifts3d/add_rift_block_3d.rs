@@ -0,0 +1,92 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model3d::fault_volumes::FaultColumns;
+use crate::model3d::shifts3d::add_fault_3d::fault_columns;
+use crate::types::FillValue;
+use crate::types::generation_params::Params3D;
+use crate::types::shifts::{Fault3D, RiftBlock3D, RiftType};
+
+/// Moves block between two bounding faults vertically as a whole: down by throw for graben and up
+/// for horst, bounding faults move together with the block. Every border takes moved value if it
+/// was inside the block, keeps its value if it stays outside the moved block, otherwise border ends
+/// at the moved bounding fault. Horizontal part of slip is not modelled, so side blocks are not moved.
+pub fn add_rift_block<T: FillValue>(
+    params: &Params3D<T>,
+    borders: &mut [Vec<Vec<i32>>],
+    block: &RiftBlock3D,
+    max_depth: &mut i32
+) -> Result<(), &'static str> {
+    #[cfg(debug_assertions)]
+    trace!("Starting applying rift block: {:?}", block);
+
+    let faults = block.bounding_faults()?;
+
+    for border in borders.iter_mut() {
+        for (y_num, y) in params.y_axis().centers().iter().enumerate() {
+            for (x_num, x) in params.x_axis().centers().iter().enumerate() {
+                let source_value = border[y_num][x_num];
+
+                let new_value = if block.contains(&faults, *x, *y, source_value as f32) {
+                    source_value + block_shift(block, *x, *y, source_value as f32).round() as i32
+                } else if !moved_block_contains(block, &faults, *x, *y, source_value as f32) {
+                    source_value
+                } else {
+                    // Border outside the block is cut by moved block, so it ends at the nearest moved fault
+                    moved_faults_depths(block, &faults, *x, *y)
+                        .min_by(|a, b| (a - source_value as f32).abs().total_cmp(&(b - source_value as f32).abs()))
+                        .map(|depth| depth.round() as i32)
+                        .unwrap_or(source_value)
+                };
+
+                let new_value = new_value.max(0);
+                if new_value > *max_depth {
+                    *max_depth = new_value
+                }
+                border[y_num][x_num] = new_value;
+            }
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    trace!("Rift block has been applied");
+    Ok(())
+}
+
+/// Returns surfaces of both bounding faults for fault volumes, they are moved together with the block
+pub fn rift_block_fault_columns<T: FillValue>(params: &Params3D<T>, block: &RiftBlock3D) -> Result<[FaultColumns; 2], &'static str> {
+    let faults = block.bounding_faults()?;
+    let x_centers = params.x_axis().centers();
+
+    let columns = faults.clone().map(|fault| {
+        let mut columns = fault_columns(params, &fault);
+        for (y_line, y) in columns.iter_mut().zip(params.y_axis().centers()) {
+            for ((depth, _), x) in y_line.iter_mut().zip(x_centers) {
+                if depth.is_finite() {
+                    *depth += block_shift(block, *x, *y, *depth);
+                }
+            }
+        }
+        columns
+    });
+    Ok(columns)
+}
+
+// Vertical movement of block at the point, positive values move block down
+fn block_shift(block: &RiftBlock3D, x: f32, y: f32, z: f32) -> f32 {
+    let direction = match block.rift_type() {
+        RiftType::Graben => 1.0,
+        RiftType::Horst => -1.0,
+    };
+    direction * block.throw() as f32 * block.throw_factor(x, y, z)
+}
+
+fn moved_block_contains(block: &RiftBlock3D, faults: &[Fault3D; 2], x: f32, y: f32, z: f32) -> bool {
+    block.contains(faults, x, y, z - block_shift(block, x, y, z))
+}
+
+fn moved_faults_depths<'a>(block: &'a RiftBlock3D, faults: &'a [Fault3D; 2], x: f32, y: f32) -> impl Iterator<Item = f32> + 'a {
+    faults.iter()
+        .filter_map(move |fault| fault.plane_depth(x, y))
+        .map(move |depth| depth + block_shift(block, x, y, depth))
+}
@@ -1,13 +1,99 @@
 #[cfg(debug_assertions)]
 use log::trace;
 
+use crate::model3d::fault_volumes::FaultColumns;
+use crate::types::FillValue;
 use crate::types::generation_params::Params3D;
 use crate::types::shifts::{Shift3D, ShiftTypes};
 
-pub fn add_shift(params: &Params3D, borders: &mut [Vec<Vec<i32>>], now_shift: &Shift3D, max_depth: &mut i32) {
+// Columns which are moved by shift have slice depth and shift force, other columns can be moved
+// only by fault drag. Slice depth of static column is the same as for moving column at the same
+// distance from shift's lines, so drag bends only borders of faulted interval
+#[derive(Clone, Copy)]
+enum ShiftColumn {
+    Moving { slice_depth: i32, shift_force: i32 },
+    Static { slice_depth: i32, drag_force: i32 },
+}
+
+// Shift's columns, stored as Y->X like borders
+type ShiftColumns = Vec<Vec<ShiftColumn>>;
+
+pub fn add_shift<T: FillValue>(params: &Params3D<T>, borders: &mut [Vec<Vec<i32>>], now_shift: &Shift3D, max_depth: &mut i32) {
     #[cfg(debug_assertions)]
     trace!("Starting generating slice");
 
+    let (shift_columns, is_lift) = shift_columns(params, now_shift, *max_depth);
+
+    for (y_num, y_columns) in shift_columns.iter().enumerate() {
+        for (x_num, column) in y_columns.iter().enumerate() {
+            let (slice_depth, shift_force) = match *column {
+                ShiftColumn::Moving { slice_depth, shift_force } => (slice_depth, shift_force),
+                ShiftColumn::Static { drag_force: 0, .. } => continue,
+                ShiftColumn::Static { slice_depth, drag_force } => {
+                    // Faulted part of column is bent in direction of shift (or opposite for reverse drag)
+                    for border in borders.iter_mut() {
+                        let now_border = &mut border[y_num][x_num];
+                        if (is_lift && *now_border < slice_depth) || (!is_lift && *now_border > slice_depth) {
+                            continue;
+                        }
+                        *now_border = (*now_border + if is_lift { -drag_force } else { drag_force }).max(0);
+                        if *now_border > *max_depth {
+                            *max_depth = *now_border
+                        }
+                    }
+                    continue;
+                }
+            };
+
+            for border in borders.iter_mut() {
+                let now_border = &mut border[y_num][x_num];
+
+                if is_lift {
+                    if *now_border < slice_depth {
+                        continue;
+                    }
+                    let mut now_shift_force = *now_border - slice_depth;
+                    if now_shift_force > shift_force {
+                        now_shift_force = shift_force
+                    }
+                    *now_border -= now_shift_force;
+                } else {
+                    if *now_border > slice_depth {
+                        continue;
+                    }
+                    let mut now_shift_force = slice_depth - *now_border;
+                    if now_shift_force > shift_force {
+                        now_shift_force = shift_force
+                    }
+                    *now_border += now_shift_force;
+                    if *now_border > *max_depth {
+                        *max_depth = *now_border
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    trace!("Slice generation has finished");
+}
+
+/// Returns surface of shift for fault volumes: moved part of column is above slice depth for descents
+/// and below it for lifts, columns outside moving region are not crossed
+pub fn shift_fault_columns<T: FillValue>(params: &Params3D<T>, now_shift: &Shift3D, max_depth: i32) -> FaultColumns {
+    let (shift_columns, is_lift) = shift_columns(params, now_shift, max_depth);
+
+    shift_columns.iter().map(|y_columns| {
+        y_columns.iter().map(|column| match (column, is_lift) {
+            (ShiftColumn::Moving { slice_depth, .. }, false) => (*slice_depth as f32 + 0.5, true),
+            (ShiftColumn::Moving { slice_depth, .. }, true) => (*slice_depth as f32 - 0.5, false),
+            (ShiftColumn::Static { .. }, false) => (f32::NEG_INFINITY, true),
+            (ShiftColumn::Static { .. }, true) => (f32::INFINITY, false),
+        }).collect()
+    }).collect()
+}
+
+fn shift_columns<T: FillValue>(params: &Params3D<T>, now_shift: &Shift3D, max_depth: i32) -> (ShiftColumns, bool) {
     let now_shift_angle_y = now_shift.angle_y();
     let now_shift_angle_x = now_shift.angle_x();
     let y_line_y_pos = now_shift.pos_y();
@@ -21,15 +107,18 @@ pub fn add_shift(params: &Params3D, borders: &mut [Vec<Vec<i32>>], now_shift: &S
     let y_centers = params.y_axis().centers();
     let x_centers = params.x_axis().centers();
 
-    let (crossed_point_x, crossed_point_y) = {
+    let (crossed_point_x_f32, crossed_point_y_f32) = {
         let y_line_coef = 1.0 / ((180.0 - now_shift_angle_y).to_radians().tan());
         let x_line_coef = (180.0 - now_shift_angle_x).to_radians().tan();
 
         let x_cross = (y_line_y_pos + x_line_coef * x_line_x_pos) / (x_line_coef - y_line_coef);
         let y_cross = y_line_coef * x_cross + y_line_y_pos;
         
-        (x_cross.round() as i32, y_cross.round() as i32)
+        (x_cross, y_cross)
     };
+    let (crossed_point_x, crossed_point_y) = (crossed_point_x_f32.round() as i32, crossed_point_y_f32.round() as i32);
+    let displacement_profile = now_shift.displacement_profile();
+    let drag = now_shift.drag();
     
     let new_angle_y_tan = (if now_shift_angle_y <= 90.0 {
         now_shift_angle_y
@@ -80,6 +169,8 @@ pub fn add_shift(params: &Params3D, borders: &mut [Vec<Vec<i32>>], now_shift: &S
         ShiftTypes::InnerDescent | ShiftTypes::OuterDescent => false,
     };
 
+    let mut shift_columns: ShiftColumns = vec![vec![ShiftColumn::Static { slice_depth: 0, drag_force: 0 }; x_centers.len()]; y_centers.len()];
+
     for (y_num, y) in y_centers.iter().enumerate() {
         for (x_num, x) in x_centers.iter().enumerate() {
             // State 1 - left lower part
@@ -94,15 +185,17 @@ pub fn add_shift(params: &Params3D, borders: &mut [Vec<Vec<i32>>], now_shift: &S
             state += if *y <= y_line_y_point { 1 } else { 3 };
             state += if *x <= x_line_x_point { 0 } else { 1 };
             
-            if (is_inner && state != target_state) || (!is_inner && state == target_state) {
-                continue;
-            }
+            // Inner shifts move target state, outer shifts move everything except it
+            let is_moving = is_inner == (state == target_state);
 
             let x_minimal_len = (*x - x_line_x_point).abs();
             let y_minimal_len = (*y - y_line_y_point).abs();
 
-            // This block deiiermines minimal distinance beetween point and working target state.
-            let minimal_len = if !is_inner {
+            // This block determines minimal distance between point and the other part of shift.
+            // Inside target state it's distance to the nearest line, outside - to the target state
+            let minimal_len = if state == target_state {
+                x_minimal_len.min(y_minimal_len)
+            } else {
                 match state + target_state {
                     3 | 7 => x_minimal_len,
                     4 | 6 => y_minimal_len,
@@ -111,47 +204,31 @@ pub fn add_shift(params: &Params3D, borders: &mut [Vec<Vec<i32>>], now_shift: &S
                             .sqrt()
                     }
                 }
-            } else if x_minimal_len < y_minimal_len {
-                x_minimal_len
-            } else {
-                y_minimal_len
+            };
+
+            // Shift dies out with distance from crossing point of shift's lines
+            let center_len = ((*x - crossed_point_x_f32).powi(2) + (*y - crossed_point_y_f32).powi(2)).sqrt();
+            let shift_force = (shift_force as f32 * displacement_profile.factor(center_len, 0.0)).round() as i32;
+
+            let (shift_force, drag_force) = match drag {
+                Some(drag) => drag.forces(shift_force, minimal_len),
+                None => (shift_force, 0),
             };
 
             let mut slice_depth = ((now_shift_angle_z_tan * minimal_len).round() as i32).abs();
 
             if is_lift {
-                slice_depth = *max_depth - slice_depth
+                slice_depth = max_depth - slice_depth
             }
 
-            for border in borders.iter_mut() {
-                let now_border = &mut border[y_num][x_num];
-
-                if is_lift {
-                    if *now_border < slice_depth {
-                        continue;
-                    }
-                    let mut now_shift_force = *now_border - slice_depth;
-                    if now_shift_force > shift_force {
-                        now_shift_force = shift_force
-                    }
-                    *now_border -= now_shift_force;
-                } else {
-                    if *now_border > slice_depth {
-                        continue;
-                    }
-                    let mut now_shift_force = slice_depth - *now_border;
-                    if now_shift_force > shift_force {
-                        now_shift_force = shift_force
-                    }
-                    *now_border += now_shift_force;
-                    if *now_border > *max_depth {
-                        *max_depth = *now_border
-                    }
-                }
+            if !is_moving {
+                shift_columns[y_num][x_num] = ShiftColumn::Static { slice_depth, drag_force };
+                continue;
             }
+
+            shift_columns[y_num][x_num] = ShiftColumn::Moving { slice_depth, shift_force };
         }
     }
 
-    #[cfg(debug_assertions)]
-    trace!("Slice generation has finished");
+    (shift_columns, is_lift)
 }
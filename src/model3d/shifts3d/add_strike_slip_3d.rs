@@ -0,0 +1,51 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model3d::fault_volumes::FaultColumns;
+use crate::types::FillValue;
+use crate::types::generation_params::Params3D;
+use crate::types::shifts::StrikeSlipFault3D;
+
+/// Moves block to the right of strike-slip fault horizontally, borders of moving block are resampled
+/// from the nearest source blocks. Blocks which source is outside model take the nearest edge value
+pub fn add_strike_slip_fault<T: FillValue>(params: &Params3D<T>, borders: &mut [Vec<Vec<i32>>], fault: &StrikeSlipFault3D) {
+    #[cfg(debug_assertions)]
+    trace!("Starting applying strike-slip fault: {:?}", fault);
+
+    let x_ax = params.x_axis();
+    let y_ax = params.y_axis();
+
+    for border in borders.iter_mut() {
+        let source_border = border.clone();
+
+        for (y_num, y) in y_ax.centers().iter().enumerate() {
+            for (x_num, x) in x_ax.centers().iter().enumerate() {
+                if fault.signed_distance(*x, *y) <= 0.0 {
+                    continue;
+                }
+
+                let (shift_x, shift_y) = fault.displacement(*x, *y, source_border[y_num][x_num] as f32);
+                let source_x_num = x_ax.nearest_block(x - shift_x);
+                let source_y_num = y_ax.nearest_block(y - shift_y);
+
+                border[y_num][x_num] = source_border[source_y_num][source_x_num];
+            }
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    trace!("Strike-slip fault has been applied");
+}
+
+/// Returns surface of strike-slip fault for fault volumes, moving block plays role of hanging wall
+pub fn strike_slip_fault_columns<T: FillValue>(params: &Params3D<T>, fault: &StrikeSlipFault3D) -> FaultColumns {
+    params.y_axis().centers().iter().map(|y| {
+        params.x_axis().centers().iter().map(|x| {
+            if fault.signed_distance(*x, *y) > 0.0 {
+                (f32::INFINITY, true)
+            } else {
+                (f32::NEG_INFINITY, true)
+            }
+        }).collect()
+    }).collect()
+}
@@ -1 +1,6 @@
 pub mod add_shift_3d;
+pub mod add_fault_3d;
+pub mod add_listric_fault_3d;
+pub mod add_rift_block_3d;
+pub mod add_strike_slip_3d;
+pub mod add_fold_3d;
@@ -0,0 +1,205 @@
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::model3d::Model3D;
+use crate::types::{Axis, FillValue, UpscaleMethod};
+
+/// Heterogeneity of fine values inside every coarse cell (X->Y->Z), empty if model has no values
+#[derive(Debug, Clone)]
+pub struct UpscaleStats {
+    min: Vec<Vec<Vec<f64>>>,
+    max: Vec<Vec<Vec<f64>>>,
+    std_dev: Vec<Vec<Vec<f64>>>,
+    majority_fraction: Vec<Vec<Vec<f32>>>,
+}
+
+impl UpscaleStats {
+    pub fn min(&self) -> &Vec<Vec<Vec<f64>>> {
+        &self.min
+    }
+
+    pub fn max(&self) -> &Vec<Vec<Vec<f64>>> {
+        &self.max
+    }
+
+    /// Returns population standard deviation of fine values
+    pub fn std_dev(&self) -> &Vec<Vec<Vec<f64>>> {
+        &self.std_dev
+    }
+
+    /// Returns part of fine cells which belong to layer of coarse cell, empty if model has no mask
+    pub fn majority_fraction(&self) -> &Vec<Vec<Vec<f32>>> {
+        &self.majority_fraction
+    }
+}
+
+impl<T: FillValue> Model3D<T> {
+    /// Upscales model to coarse cells of `factors` fine blocks along x, y and z (the last cells can
+    /// be smaller). Values are averaged with method, mask is assigned by majority vote (the smallest
+    /// layer wins ties) and borders are taken from coarse mask. Fluid mask and fault volumes are
+    /// not upscaled, so they are empty in coarse model. Models with thrusts can't be upscaled,
+    /// because their borders don't contain thrusts
+    pub fn upscale(&self, factors: [usize; 3], method: UpscaleMethod) -> Result<(Model3D<T>, UpscaleStats), &'static str> {
+        self.check_borders_describe_model()?;
+
+        if factors.contains(&0) {
+            return Err("Upscaling factors must be bigger than zero")
+        }
+
+        #[cfg(debug_assertions)]
+        trace!("Upscaling model by {:?} with {:?} averaging", factors, method);
+
+        #[cfg(debug_assertions)]
+        if !self.fluid_mask.is_empty() || !self.fault_mask.is_empty() {
+            trace!("Fluid mask and fault volumes are discarded by upscaling");
+        }
+
+        if method != UpscaleMethod::Arithmetic && self.model.iter().flatten().flatten().any(|value| value.to_f64() < 0.0) {
+            return Err("Harmonic, geometric and Backus averages need non-negative values")
+        }
+
+        let x_cells = coarse_ranges(self.params.x_axis().blocks_count(), factors[0]);
+        let y_cells = coarse_ranges(self.params.y_axis().blocks_count(), factors[1]);
+        let z_cells = coarse_ranges(self.max_depth as usize, factors[2]);
+
+        let model_ex = !self.model.is_empty();
+        let mask_ex = !self.model_mask.is_empty();
+
+        let coarse_volume = |value: f64| vec![vec![vec![value; z_cells.len()]; y_cells.len()]; x_cells.len()];
+        let (mut min, mut max, mut std_dev) = if model_ex {
+            (coarse_volume(0.0), coarse_volume(0.0), coarse_volume(0.0))
+        } else {
+            (Vec::new(), Vec::new(), Vec::new())
+        };
+        let mut majority_fraction = if mask_ex {
+            vec![vec![vec![0.0f32; z_cells.len()]; y_cells.len()]; x_cells.len()]
+        } else {
+            Vec::new()
+        };
+
+        let mut model: Vec<Vec<Vec<T>>> = Vec::with_capacity(if model_ex {x_cells.len()} else {0});
+        let mut model_mask: Vec<Vec<Vec<u8>>> = Vec::with_capacity(if mask_ex {x_cells.len()} else {0});
+
+        for (x_cell, (x_start, x_end)) in x_cells.iter().enumerate() {
+            let mut model_x: Vec<Vec<T>> = Vec::with_capacity(y_cells.len());
+            let mut mask_x: Vec<Vec<u8>> = Vec::with_capacity(y_cells.len());
+
+            for (y_cell, (y_start, y_end)) in y_cells.iter().enumerate() {
+                let mut model_y: Vec<T> = Vec::with_capacity(z_cells.len());
+                let mut mask_y: Vec<u8> = Vec::with_capacity(z_cells.len());
+
+                for (z_cell, (z_start, z_end)) in z_cells.iter().enumerate() {
+                    if model_ex {
+                        let columns: Vec<Vec<f64>> = (*x_start..*x_end).flat_map(|x_num| {
+                            (*y_start..*y_end).map(move |y_num| (x_num, y_num))
+                        }).map(|(x_num, y_num)| {
+                            self.model[x_num][y_num][*z_start..*z_end].iter().map(|value| value.to_f64()).collect()
+                        }).collect();
+
+                        let values: Vec<f64> = columns.iter().flatten().copied().collect();
+                        let mean = arithmetic_mean(&values);
+
+                        min[x_cell][y_cell][z_cell] = values.iter().copied().fold(f64::MAX, f64::min);
+                        max[x_cell][y_cell][z_cell] = values.iter().copied().fold(f64::MIN, f64::max);
+                        std_dev[x_cell][y_cell][z_cell] = arithmetic_mean(
+                            &values.iter().map(|value| (value - mean).powi(2)).collect::<Vec<f64>>()
+                        ).sqrt();
+
+                        let average = match method {
+                            UpscaleMethod::Arithmetic => mean,
+                            UpscaleMethod::Harmonic => harmonic_mean(&values),
+                            UpscaleMethod::Geometric => {
+                                arithmetic_mean(&values.iter().map(|value| value.ln()).collect::<Vec<f64>>()).exp()
+                            },
+                            UpscaleMethod::Backus => {
+                                arithmetic_mean(&columns.iter().map(|column| harmonic_mean(column)).collect::<Vec<f64>>())
+                            },
+                        };
+                        model_y.push(T::from_f64(average));
+                    }
+
+                    if mask_ex {
+                        let mut counts = [0usize; 256];
+                        for x_num in *x_start..*x_end {
+                            for y_num in *y_start..*y_end {
+                                for layer in &self.model_mask[x_num][y_num][*z_start..*z_end] {
+                                    counts[*layer as usize] += 1;
+                                }
+                            }
+                        }
+
+                        // max_by_key returns the last maximum, so counts are reversed for the smallest layer
+                        let (layer, count) = counts.iter().enumerate().rev().max_by_key(|(_, count)| **count).unwrap();
+                        let cells_count = (x_end - x_start) * (y_end - y_start) * (z_end - z_start);
+                        majority_fraction[x_cell][y_cell][z_cell] = *count as f32 / cells_count as f32;
+                        mask_y.push(layer as u8);
+                    }
+                }
+                model_x.push(model_y);
+                mask_x.push(mask_y);
+            }
+            if model_ex {
+                model.push(model_x);
+            }
+            if mask_ex {
+                model_mask.push(mask_x);
+            }
+        }
+
+        // Borders are taken from coarse mask, so they match it: border of layer is below the last
+        // cell of this or shallower layer (intrusions inside layer don't move it).
+        // Without mask borders are averaged inside coarse column and measured in coarse cells
+        let borders: Vec<Vec<Vec<i32>>> = (0..self.borders.len()).map(|layer| {
+            y_cells.iter().enumerate().map(|(y_cell, (y_start, y_end))| {
+                x_cells.iter().enumerate().map(|(x_cell, (x_start, x_end))| {
+                    if mask_ex {
+                        return model_mask[x_cell][y_cell].iter()
+                            .rposition(|cell_layer| *cell_layer as usize <= layer)
+                            .map_or(0, |z_cell| z_cell + 1) as i32
+                    }
+
+                    let borders: Vec<f64> = self.borders[layer][*y_start..*y_end].iter()
+                        .flat_map(|y_line| y_line[*x_start..*x_end].iter().map(|border| *border as f64))
+                        .collect();
+                    (arithmetic_mean(&borders) / factors[2] as f64).round() as i32
+                }).collect()
+            }).collect()
+        }).collect();
+
+        let mut params = self.params.clone();
+        params.set_x_axis(coarse_axis(self.params.x_axis(), factors[0]));
+        params.set_y_axis(coarse_axis(self.params.y_axis(), factors[1]));
+
+        let upscaled = Model3D::new(
+            model,
+            model_mask,
+            borders,
+            self.layers_filling_values.clone(),
+            z_cells.len() as i32,
+            params,
+        );
+
+        Ok((upscaled, UpscaleStats { min, max, std_dev, majority_fraction }))
+    }
+}
+
+fn coarse_ranges(size: usize, factor: usize) -> Vec<(usize, usize)> {
+    (0..size).step_by(factor).map(|start| (start, (start + factor).min(size))).collect()
+}
+
+fn coarse_axis(axis: &Axis, factor: usize) -> Axis {
+    let edges = axis.axis();
+    let mut coarse_edges: Vec<f32> = edges.iter().step_by(factor).copied().collect();
+    if !(edges.len() - 1).is_multiple_of(factor) {
+        coarse_edges.push(edges[edges.len() - 1]);
+    }
+    Axis::create_from_edges(coarse_edges).unwrap()
+}
+
+fn arithmetic_mean(values: &[f64]) -> f64 {
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn harmonic_mean(values: &[f64]) -> f64 {
+    values.len() as f64 / values.iter().map(|value| 1.0 / value).sum::<f64>()
+}
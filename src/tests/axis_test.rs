@@ -56,3 +56,16 @@ fn gen_axis_tests() {
 
     // TODO: Add more tests
 }
+
+#[test]
+fn find_element_smaller_tests() {
+    let ax = Axis::generate_axis_hard(1.0, 3.1, None).unwrap();
+    assert_eq!(ax.find_element_smaller(1.0), Some(0));
+    assert_eq!(ax.find_element_smaller(1.99), Some(0));
+    assert_eq!(ax.find_element_smaller(2.0), Some(1));
+    assert_eq!(ax.find_element_smaller(3.1), Some(2));
+    assert_eq!(ax.find_element_smaller(0.9), None);
+    assert_eq!(ax.find_element_smaller(3.2), None);
+    assert_eq!(ax.find_element_smaller(f32::NAN), None);
+    assert_eq!(ax.find_element_smaller(f32::INFINITY), None);
+}
@@ -0,0 +1,390 @@
+use super::types::{Axis, LayersDist};
+use super::types::shifts::{Fault3D, FaultSense, FaultPopulation, OrientationSet, RiftBlock3D, RiftType};
+use super::types::shifts::{Shift3D, FaultDrag, DragType, StrikeSlipFault3D, LateralSense};
+use super::types::generation_params::Params3D;
+use super::model3d::generate_model;
+
+fn flat_params() -> Params3D {
+    let mut params = Params3D::new();
+    params.set_x_axis(Axis::generate_axis(0.0, 10.0, None).unwrap());
+    params.set_y_axis(Axis::generate_axis(0.0, 4.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![10, 10, 10]).unwrap());
+    params
+}
+
+#[test]
+fn vertical_fault_tests() {
+    let mut params = flat_params();
+
+    let mut fault = Fault3D::new();
+    fault.set_pos_x(5.0);
+    fault.set_strike(0.0).unwrap();
+    fault.set_dip(90.0).unwrap();
+    fault.set_throw(4).unwrap();
+    params.add_fault(fault.clone());
+
+    let model = generate_model(params.clone()).unwrap();
+    assert_eq!(model.borders()[0][2], vec![10, 10, 10, 10, 10, 14, 14, 14, 14, 14]);
+    assert_eq!(model.max_depth(), 34);
+
+    let mut params = flat_params();
+    fault.set_fault_sense(FaultSense::Reverse);
+    params.add_fault(fault);
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.borders()[1][0], vec![20, 20, 20, 20, 20, 16, 16, 16, 16, 16]);
+}
+
+#[test]
+fn dipping_fault_tests() {
+    let mut params = flat_params();
+
+    let mut fault = Fault3D::new();
+    fault.set_pos_x(5.0);
+    fault.set_pos_z(10.0);
+    fault.set_strike(0.0).unwrap();
+    fault.set_dip(45.0).unwrap();
+    fault.set_throw(2).unwrap();
+    params.add_fault(fault);
+
+    let model = generate_model(params).unwrap();
+    for y_line in &model.borders()[0] {
+        assert_eq!(*y_line, vec![10, 10, 10, 10, 10, 11, 12, 12, 12, 12]);
+    }
+    for y_line in &model.borders()[1] {
+        assert_eq!(*y_line, vec![20; 10]);
+    }
+}
+
+#[test]
+fn fault_displacement_profile_tests() {
+    use super::types::shifts::DisplacementProfile;
+
+    let mut params = flat_params();
+
+    let mut fault = Fault3D::new();
+    fault.set_pos_x(5.0);
+    fault.set_pos_y(2.0);
+    fault.set_dip(90.0).unwrap();
+    fault.set_throw(10).unwrap();
+    assert!(fault.set_displacement_profile(DisplacementProfile::Elliptical { half_length: 0.0, half_height: 1.0 }).is_err());
+    fault.set_displacement_profile(DisplacementProfile::Elliptical { half_length: 2.0, half_height: 1000.0 }).unwrap();
+    params.add_fault(fault);
+
+    let model = generate_model(params).unwrap();
+    let hanging_wall: Vec<i32> = model.borders()[0].iter().map(|y_line| y_line[9]).collect();
+    assert_eq!(hanging_wall, vec![14, 19, 19, 14]);
+
+    let profile = DisplacementProfile::Custom { half_length: 1.0, half_height: 1.0, profile: vec![[0.0, 1.0], [0.5, 0.5]] };
+    assert_eq!(profile.factor(0.25, 0.0), 0.75);
+    assert_eq!(profile.factor(0.75, 0.0), 0.25);
+    assert_eq!(profile.factor(1.0, 0.0), 0.0);
+
+    // Down dip distance is measured along plane of dipping fault
+    let mut fault = Fault3D::new();
+    fault.set_dip(30.0).unwrap();
+    fault.set_displacement_profile(DisplacementProfile::Elliptical { half_length: 10.0, half_height: 10.0 }).unwrap();
+    assert!((fault.throw_factor(5.0, 5.0, 4.0) - (1.0 - 0.8f32.powi(2))).abs() < 1e-5);
+}
+
+#[test]
+fn listric_fault_tests() {
+    use super::types::shifts::{ListricFault3D, TraceShape};
+
+    let mut fault = ListricFault3D::new();
+    fault.set_trace(vec![[2.0, 0.0], [2.0, 4.0]]).unwrap();
+    fault.set_surface_dip(80.0).unwrap();
+    fault.set_detachment_depth(100.0).unwrap();
+    fault.set_throw(4).unwrap();
+
+    // Dip flattens with depth and fault never goes below detachment
+    let dip_tan = 80f32.to_radians().tan();
+    assert_eq!(fault.fault_depth(0.0), 0.0);
+    assert!(fault.fault_depth(1.0) < dip_tan);
+    assert!(fault.fault_depth(1000.0) <= 100.0);
+
+    let mut params = flat_params();
+    params.add_listric_fault(fault.clone());
+    let model = generate_model(params).unwrap();
+
+    // Footwall is not moved, hanging wall is moved down, but less than throw far from fault (rollover)
+    for y_line in &model.borders()[2] {
+        assert_eq!(y_line[0], 30);
+        assert!(30 < y_line[9] && y_line[9] < 34);
+    }
+
+    fault.set_trace_shape(TraceShape::Spline);
+    fault.set_trace(vec![[2.0, 0.0], [3.0, 2.0], [2.0, 4.0]]).unwrap();
+    let points = fault.trace_points();
+    assert_eq!(points[0], [2.0, 0.0]);
+    assert_eq!(points[points.len() - 1], [2.0, 4.0]);
+    assert!(points.len() > 3);
+
+    assert!(fault.set_trace_depth(100.0).is_err());
+    fault.set_trace_depth(10.0).unwrap();
+    assert!(fault.set_detachment_depth(10.0).is_err());
+
+    // Repeated point of deserialized trace is skipped
+    let mut fault_value = serde_json::to_value(&fault).unwrap();
+    fault_value["trace"] = serde_json::json!([[2.0, 0.0], [2.0, 0.0], [2.0, 4.0]]);
+    fault_value["trace_shape"] = serde_json::to_value(TraceShape::Polyline).unwrap();
+    let fault: ListricFault3D = serde_json::from_value(fault_value).unwrap();
+    let mut params = flat_params();
+    params.add_listric_fault(fault);
+    let model = generate_model(params).unwrap();
+    for y_line in &model.borders()[2] {
+        assert_eq!(y_line[0], 30);
+        assert!(30 < y_line[9]);
+    }
+}
+
+#[test]
+fn fault_volumes_tests() {
+    let mut params = flat_params();
+    params.set_fault_volumes_needed(true);
+
+    let mut fault = Fault3D::new();
+    fault.set_pos_x(5.0);
+    fault.set_strike(0.0).unwrap();
+    fault.set_dip(90.0).unwrap();
+    fault.set_throw(4).unwrap();
+    params.add_fault(fault);
+
+    let model = generate_model(params).unwrap();
+    let (fault_mask, fault_blocks) = (model.fault_mask(), model.fault_blocks());
+    assert_eq!(fault_mask.len(), 10);
+    assert_eq!(fault_mask[0][0].len(), 34);
+
+    for y in 0..4 {
+        assert_eq!(fault_mask[4][y][0], 1);
+        assert_eq!(fault_mask[5][y][20], 1);
+        assert_eq!(fault_mask[3][y][20], 0);
+        assert_eq!(fault_blocks[0][y][0], fault_blocks[4][y][33]);
+        assert_ne!(fault_blocks[4][y][0], fault_blocks[5][y][0]);
+    }
+    assert_eq!(model.fault_distance()[4][0][10], 0.0);
+    assert_eq!(model.fault_distance()[1][0][10], 3.0);
+
+    let model = generate_model(flat_params()).unwrap();
+    assert!(model.fault_mask().is_empty());
+
+    let mut params = flat_params();
+    params.set_fault_volumes_needed(true);
+    let model = generate_model(params.clone()).unwrap();
+    assert!(model.fault_distance().is_empty());
+    assert!(model.fault_blocks().iter().flatten().flatten().all(|id| *id == 0));
+
+    // Surface of older fault is moved down together with hanging wall of younger one
+    let mut dipping_fault = Fault3D::new();
+    dipping_fault.set_pos_x(3.0);
+    dipping_fault.set_strike(0.0).unwrap();
+    dipping_fault.set_dip(60.0).unwrap();
+    dipping_fault.set_throw(2).unwrap();
+    params.add_fault(dipping_fault);
+
+    let fault_top = |model: &crate::model3d::Model3D, x: usize| model.fault_mask()[x][0].iter().position(|id| *id == 1).unwrap();
+    let model = generate_model(params.clone()).unwrap();
+    let (left_top, right_top) = (fault_top(&model, 5), fault_top(&model, 8));
+
+    let mut fault = Fault3D::new();
+    fault.set_pos_x(7.0);
+    fault.set_strike(0.0).unwrap();
+    fault.set_dip(90.0).unwrap();
+    fault.set_throw(4).unwrap();
+    params.add_fault(fault);
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(fault_top(&model, 5), left_top);
+    assert_eq!(fault_top(&model, 8), right_top + 4);
+    assert_eq!(model.fault_blocks().iter().flatten().flatten().max(), Some(&3));
+}
+
+#[test]
+fn fault_population_tests() {
+    let mut population = FaultPopulation::new();
+    population.set_faults_count(Some(8));
+    population.set_length_range([2.0, 8.0]).unwrap();
+    population.set_reverse_fraction(1.0).unwrap();
+
+    let mut orientation = OrientationSet::new();
+    orientation.set_strike([350.0, 10.0]).unwrap();
+    orientation.set_dip([60.0, 70.0]).unwrap();
+    population.set_orientation_sets(vec![orientation]).unwrap();
+
+    let mut params = flat_params();
+    params.set_fault_population(Some(population.clone()));
+    let model = generate_model(params).unwrap();
+
+    assert_eq!(model.params().faults().len(), 8);
+    for fault in model.params().faults() {
+        assert!((0.0..=10.0).contains(&fault.pos_x()) && (0.0..=4.0).contains(&fault.pos_y()));
+        assert!(fault.strike() >= 350.0 || fault.strike() <= 10.0);
+        assert!((60.0..=70.0).contains(&fault.dip()));
+        assert!((0..=1).contains(&fault.throw()));
+        assert_eq!(fault.fault_sense(), FaultSense::Reverse);
+    }
+
+    // Generated faults replace population, so model can be generated again from its params
+    assert!(model.params().fault_population().is_none());
+    let regenerated = generate_model(model.params().clone()).unwrap();
+    assert_eq!(regenerated.params().faults().len(), 8);
+
+    // Long vertical faults with throw of 4 samples move borders, faults are applied only once
+    let mut long_population = population.clone();
+    long_population.set_faults_count(Some(5));
+    long_population.set_length_range([40.0, 40.0]).unwrap();
+    long_population.set_throw_scaling(4.0, 0.0).unwrap();
+    long_population.set_height_ratio(10.0).unwrap();
+    let mut vertical = OrientationSet::new();
+    vertical.set_dip([90.0, 90.0]).unwrap();
+    long_population.set_orientation_sets(vec![vertical]).unwrap();
+
+    let mut params = flat_params();
+    params.set_fault_population(Some(long_population));
+    let model = generate_model(params).unwrap();
+    assert!(model.params().faults().iter().all(|fault| fault.throw() == 4));
+    assert!(model.borders()[0].iter().flatten().any(|value| *value != 10));
+    assert_eq!(generate_model(model.params().clone()).unwrap().borders(), model.borders());
+
+    population.set_faults_count(None);
+    population.set_density(0.5).unwrap();
+    let faults = population.generate_faults(&Axis::generate_axis(0.0, 10.0, None).unwrap(),
+        &Axis::generate_axis(0.0, 4.0, None).unwrap(), 30).unwrap();
+    assert_eq!(faults.len(), 20);
+
+    assert!(population.set_length_range([5.0, 1.0]).is_err());
+    assert!(population.set_orientation_sets(Vec::new()).is_err());
+}
+
+#[test]
+fn rift_block_tests() {
+    let mut params = flat_params();
+
+    let mut graben = RiftBlock3D::new();
+    graben.set_pos_y(2.0);
+    graben.set_dips(90.0, 90.0).unwrap();
+    graben.set_throw(4).unwrap();
+    params.add_rift_block(graben);
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.borders()[0][1], vec![10, 10, 10, 14, 14, 14, 14, 10, 10, 10]);
+    assert_eq!(model.borders()[2][3], vec![30, 30, 30, 34, 34, 34, 34, 30, 30, 30]);
+
+    let mut params = flat_params();
+
+    let mut horst = RiftBlock3D::new();
+    horst.set_rift_type(RiftType::Horst);
+    horst.set_pos_z(10.0);
+    horst.set_throw(4).unwrap();
+    params.add_rift_block(horst.clone());
+
+    // Whole block is lifted by throw, so its top is flat and moved faults cut borders of side blocks
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.borders()[0][0], vec![10, 9, 7, 6, 6, 6, 6, 7, 9, 10]);
+
+    let mut params = flat_params();
+
+    let mut graben = RiftBlock3D::new();
+    graben.set_pos_y(2.0);
+    graben.set_pos_z(10.0);
+    graben.set_throw(4).unwrap();
+    params.add_rift_block(graben);
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.borders()[0][2], vec![10, 11, 13, 14, 14, 14, 14, 13, 11, 10]);
+
+    assert!(horst.set_dips(60.0, 0.0).is_err());
+    assert!(horst.set_width(0.0).is_err());
+}
+
+#[test]
+fn fault_drag_tests() {
+    let mut params = flat_params();
+
+    let mut shift = Shift3D::new();
+    shift.set_drag(Some(FaultDrag::new()));
+    params.add_shift(shift.clone());
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.borders()[0][0], vec![10, 10, 10, 10, 13, 27, 30, 30, 30, 30]);
+    assert_eq!(model.borders()[1][3], vec![20, 20, 20, 20, 23, 37, 40, 40, 40, 40]);
+
+    let mut drag = FaultDrag::new();
+    drag.set_drag_type(DragType::Reverse);
+    shift.set_drag(Some(drag.clone()));
+
+    let mut params = flat_params();
+    params.add_shift(shift.clone());
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.borders()[0][0], vec![10, 10, 10, 10, 7, 33, 30, 30, 30, 30]);
+
+    // Drag bends only borders of faulted interval, the deepest border is below slice of steep shift
+    shift.set_drag(Some(FaultDrag::new()));
+    shift.set_angle_z(89.0).unwrap();
+    let mut params = flat_params();
+    params.add_shift(shift);
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.borders()[0][0][4], 13);
+    assert_eq!(model.borders()[2][0][4], 30);
+    assert_eq!(model.borders()[2][0][5], 30);
+
+    assert!(drag.set_intensity(1.5).is_err());
+    assert!(drag.set_width(0.0).is_err());
+}
+
+#[test]
+fn strike_slip_fault_tests() {
+    let mut params = flat_params();
+
+    let mut fault = Fault3D::new();
+    fault.set_pos_y(2.0);
+    fault.set_strike(90.0).unwrap();
+    fault.set_dip(90.0).unwrap();
+    fault.set_throw(4).unwrap();
+    params.add_fault(fault);
+
+    let mut strike_slip = StrikeSlipFault3D::new();
+    strike_slip.set_offset(1.0).unwrap();
+    strike_slip.set_lateral_sense(LateralSense::Sinistral);
+    params.add_strike_slip_fault(strike_slip);
+
+    let model = generate_model(params).unwrap();
+    let column = |x: usize| model.borders()[0].iter().map(|y_line| y_line[x]).collect::<Vec<i32>>();
+    assert_eq!(column(2), vec![14, 14, 10, 10]);
+    assert_eq!(column(7), vec![14, 14, 14, 10]);
+}
+
+#[test]
+fn thrust_intervals_tests() {
+    let mut params = flat_params();
+
+    let mut thrust = Fault3D::new();
+    thrust.set_dip(45.0).unwrap();
+    thrust.set_throw(10).unwrap();
+    thrust.set_fault_sense(FaultSense::Reverse);
+    params.add_thrust(thrust);
+
+    let model = generate_model(params).unwrap();
+    let layers: Vec<usize> = model.intervals()[0][9].iter().map(|interval| interval.layer()).collect();
+    assert_eq!(layers, vec![1, 0, 1, 2]);
+    assert_eq!(model.intervals()[0][0].len(), 3);
+
+    // Layer 1 is repeated in hanging wall above the footwall section
+    let mask_column = &model.model_mask()[9][0];
+    assert_eq!(mask_column[..5], [1; 5]);
+    assert_eq!(mask_column[5..10], [0; 5]);
+    assert_eq!(mask_column[10..20], [1; 10]);
+    assert_eq!(model.model()[9][0].len(), 30);
+
+    // Borders don't contain thrust, so operations which read them return errors
+    assert_eq!(model.layer_at(9.5, 0.5, 12.0), Ok(1));
+    assert!(model.horizon_depth_at(0, 9.5, 0.5).is_err());
+    assert!(model.form_2d_by_nums(vec![0, 1], vec![0, 0]).is_err());
+    assert!(model.inline(0).is_err());
+    assert!(model.section_along(&[[0.0, 0.5], [9.0, 0.5]], 1.0, crate::types::Interpolation::Nearest).is_err());
+    assert!(model.resample(Axis::generate_axis(0.0, 10.0, None).unwrap(), Axis::generate_axis(0.0, 4.0, None).unwrap(),
+        Axis::generate_axis(0.0, 30.0, None).unwrap(), crate::types::ResampleMethod::Nearest).is_err());
+    assert!(model.upscale([2, 2, 2], crate::types::UpscaleMethod::Arithmetic).is_err());
+}
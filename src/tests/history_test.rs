@@ -0,0 +1,169 @@
+use super::types::{Axis, LayersDist, GeologicalEvent, Fold3D, Intrusion, ErosionSurface};
+use super::types::shifts::{Fault3D, FaultSense, Shift3D};
+use super::types::generation_params::Params3D;
+use super::model3d::generate_model;
+
+fn flat_params(layers_dist: Vec<i32>) -> Params3D {
+    let mut params = Params3D::new();
+    params.set_x_axis(Axis::generate_axis(0.0, 10.0, None).unwrap());
+    params.set_y_axis(Axis::generate_axis(0.0, 4.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(layers_dist).unwrap());
+    params
+}
+
+fn vertical_fault(throw: i32, fault_sense: FaultSense) -> Fault3D {
+    let mut fault = Fault3D::new();
+    fault.set_dip(90.0).unwrap();
+    fault.set_throw(throw).unwrap();
+    fault.set_fault_sense(fault_sense);
+    fault
+}
+
+#[test]
+fn history_faults_tests() {
+    let mut params = flat_params(vec![10, 10, 10, 10]);
+    params.add_event(GeologicalEvent::Deposit { layers_count: 2 });
+    params.add_event(GeologicalEvent::Fault(vertical_fault(4, FaultSense::Normal)));
+
+    // Fault is covered by two younger layers
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.borders()[0][0], vec![10; 10]);
+    assert_eq!(model.borders()[1][0], vec![20, 20, 20, 20, 20, 24, 24, 24, 24, 24]);
+    assert_eq!(model.borders()[3][0], vec![40, 40, 40, 40, 40, 44, 44, 44, 44, 44]);
+
+    let mut params = flat_params(vec![10, 10, 10, 10]);
+    params.add_event(GeologicalEvent::Deposit { layers_count: 2 });
+    params.add_event(GeologicalEvent::Fault(vertical_fault(12, FaultSense::Reverse)));
+    params.add_event(GeologicalEvent::Deposit { layers_count: 2 });
+
+    // Younger layers onlap uplifted block
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.borders()[0][3], vec![10, 10, 10, 10, 10, 8, 8, 8, 8, 8]);
+    assert_eq!(model.borders()[1][3], vec![20, 20, 20, 20, 20, 8, 8, 8, 8, 8]);
+}
+
+#[test]
+fn history_erosion_fold_intrusion_tests() {
+    let mut params = flat_params(vec![10, 10, 10]);
+    params.add_event(GeologicalEvent::Deposit { layers_count: 2 });
+    params.add_event(GeologicalEvent::Erode(ErosionSurface::Flat { depth: 15 }));
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.borders()[0][0], vec![15; 10]);
+    assert_eq!(model.borders()[1][0], vec![20; 10]);
+
+    let mut params = flat_params(vec![10, 10, 10]);
+    params.add_event(GeologicalEvent::Deposit { layers_count: 3 });
+    params.add_event(GeologicalEvent::Fold(Fold3D::new()));
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.borders()[0][0][2], 15);
+    assert_eq!(model.borders()[2][0][7], 25);
+
+    let mut params = flat_params(vec![10, 10, 10]);
+    params.add_event(GeologicalEvent::Deposit { layers_count: 2 });
+    params.add_event(GeologicalEvent::Intrude(Intrusion::new([5.0, 2.0, 15.0], [2.0, 10.0, 10.0], 99).unwrap()));
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.model_mask()[5][0][8], 0);
+    assert_eq!(model.model_mask()[5][0][10], 3);
+    assert_eq!(model.model()[5][0][20], 99);
+    assert_eq!(model.model_mask()[0][0][15], 1);
+
+    // Later fault moves intrusion together with host rocks
+    let mut params = flat_params(vec![10, 10, 10]);
+    params.add_event(GeologicalEvent::Deposit { layers_count: 2 });
+    params.add_event(GeologicalEvent::Intrude(Intrusion::new([5.0, 2.0, 15.0], [2.0, 10.0, 10.0], 99).unwrap()));
+    params.add_event(GeologicalEvent::Fault(vertical_fault(4, FaultSense::Normal)));
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.model_mask()[4][0][10], 3);
+    assert_eq!(model.model_mask()[4][0][24], 3);
+    assert_eq!(model.model_mask()[4][0][25], 2);
+    assert_eq!(model.model_mask()[6][0][13], 0);
+    assert_eq!(model.model_mask()[6][0][14], 3);
+    assert_eq!(model.model_mask()[6][0][24], 3);
+
+    assert!(Intrusion::new([0.0; 3], [1.0, 0.0, 1.0], 1).is_err());
+}
+
+#[test]
+fn intrusion_with_contacts_and_trends_tests() {
+    use super::types::{LayersFill, FluidContact, FluidType, PropertySubstitution, LateralTrend};
+
+    let mut params = flat_params(vec![10, 10, 10]);
+    let mut fill = LayersFill::new();
+    fill.set_values_preset(vec![vec![100], vec![200], vec![300]]).unwrap();
+    params.set_layers_fill(fill);
+    params.add_event(GeologicalEvent::Deposit { layers_count: 2 });
+    params.add_event(GeologicalEvent::Intrude(Intrusion::new([5.0, 2.0, 15.0], [2.0, 10.0, 10.0], 99).unwrap()));
+    params.add_fluid_contact(FluidContact::new(1, FluidType::Gas, 15, PropertySubstitution::Value(150)).unwrap());
+    params.set_lateral_trend(1, Some(LateralTrend::Linear { azimuth: 90.0, gradient: 0.0 }));
+    params.set_lateral_trend(2, Some(LateralTrend::Linear { azimuth: 90.0, gradient: 0.0 }));
+
+    // Intrusion has mask value of layers count, contacts and trends don't change it
+    let model = generate_model(params.clone()).unwrap();
+    assert_eq!(model.model_mask()[5][0][12], 3);
+    assert_eq!(model.model()[5][0][12], 99);
+    assert_eq!(model.fluid_mask()[5][0][12], 0);
+    assert_eq!(model.model()[0][0][12], 150);
+    assert_eq!(model.fluid_mask()[0][0][12], 1);
+
+    // Without mask intrusion is still skipped by contacts
+    params.set_mask_needed(false);
+    let model = generate_model(params).unwrap();
+    assert!(model.model_mask().is_empty());
+    assert_eq!(model.model()[5][0][12], 99);
+    assert_eq!(model.fluid_mask()[5][0][12], 0);
+    assert_eq!(model.model()[0][0][12], 150);
+}
+
+#[test]
+fn erosion_surfaces_tests() {
+    for erosion_surface in [
+        ErosionSurface::Random { min_depth: 12, max_depth: 18, max_step: Some(2) },
+        ErosionSurface::Noise { depth: 15, amplitude: 3.0, wavelength: 3.0 },
+    ] {
+        let mut params = flat_params(vec![10, 10, 10]);
+        params.add_event(GeologicalEvent::Deposit { layers_count: 2 });
+        params.add_event(GeologicalEvent::Erode(erosion_surface));
+        params.add_event(GeologicalEvent::Deposit { layers_count: 1 });
+
+        let model = generate_model(params).unwrap();
+        for y_line in &model.borders()[0] {
+            assert!(y_line.iter().all(|value| (12..=18).contains(value)));
+        }
+        assert_eq!(model.borders()[1][0], vec![20; 10]);
+
+        // Mask follows eroded relief
+        for x in 0..10 {
+            let depth = model.borders()[0][0][x] as usize;
+            assert_eq!(model.model_mask()[x][0][depth - 1], 0);
+            assert_eq!(model.model_mask()[x][0][depth], 1);
+        }
+    }
+
+    let mut params = flat_params(vec![10, 10, 10]);
+    params.add_event(GeologicalEvent::Erode(ErosionSurface::Noise { depth: 15, amplitude: 3.0, wavelength: 0.0 }));
+    assert!(generate_model(params).is_err());
+}
+
+#[test]
+fn growth_shift_tests() {
+    let mut params = flat_params(vec![10, 10, 10, 10]);
+
+    let mut shift = Shift3D::new();
+    shift.set_shift_force(4).unwrap();
+    params.add_event(GeologicalEvent::Deposit { layers_count: 2 });
+    params.add_event(GeologicalEvent::GrowthShift { shift: shift.clone(), layers_count: 2 });
+
+    // Older layers have bigger throw, syn-tectonic layers are thicker in moving block
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.borders()[0][0], vec![10, 10, 10, 10, 10, 12, 12, 12, 12, 12]);
+    assert_eq!(model.borders()[1][0], vec![20, 20, 20, 20, 20, 24, 24, 24, 24, 24]);
+    assert_eq!(model.borders()[3][0], vec![40, 40, 40, 40, 40, 44, 44, 44, 44, 44]);
+
+    let mut params = flat_params(vec![10, 10, 10, 10]);
+    params.add_event(GeologicalEvent::GrowthShift { shift, layers_count: 0 });
+    assert!(generate_model(params).is_err());
+}
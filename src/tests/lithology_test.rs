@@ -0,0 +1,71 @@
+use super::types::{Axis, LayersDist, Lithology, LithologyProperty, LayerInfo, GeologicalEvent, Intrusion, AxisExportType};
+use super::types::generation_params::Params3D;
+use super::model3d::generate_model;
+
+#[test]
+fn lithology_catalogue_tests() {
+    let lithologies = Lithology::all();
+    for (num, lithology) in lithologies.iter().enumerate() {
+        assert!(lithologies[num + 1..].iter().all(|other| other.name() != lithology.name()));
+        for property in [LithologyProperty::Velocity, LithologyProperty::Density, LithologyProperty::Porosity] {
+            let range = lithology.default_range(property);
+            assert!(0.0 <= range[0] && range[0] < range[1]);
+        }
+    }
+    assert_eq!(Lithology::Sandstone.default_range(LithologyProperty::Velocity), [2500.0, 4500.0]);
+}
+
+#[test]
+fn lithology_fill_and_export_tests() {
+    let mut params = Params3D::<f32>::with_value_type();
+    params.set_x_axis(Axis::generate_axis(0.0, 4.0, None).unwrap());
+    params.set_y_axis(Axis::generate_axis(0.0, 4.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![5, 5, 5]).unwrap());
+    params.set_layer_name(0, "cap");
+    params.set_layer_lithology(1, Lithology::Sandstone);
+    params.set_lithology_property(Some(LithologyProperty::Velocity));
+    params.add_event(GeologicalEvent::Intrude(Intrusion::new([2.0, 2.0, 12.0], [1.0, 1.0, 1.0], 7000.0).unwrap()));
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.layers_filling_values()[1], vec![2500.0, 4500.0]);
+    for (x_num, x_line) in model.model().iter().enumerate() {
+        for (y_num, column) in x_line.iter().enumerate() {
+            for (z_num, value) in column.iter().enumerate() {
+                if model.model_mask()[x_num][y_num][z_num] == 1 {
+                    assert!((2500.0..=4500.0).contains(value));
+                }
+            }
+        }
+    }
+
+    let path = std::env::temp_dir().join("grunt_layers_info_test");
+    let axes_export = vec![AxisExportType::AsSelf, AxisExportType::AsSelf, AxisExportType::AsSelf];
+    model.export_model(path.to_str().unwrap(), &["layers_info"], &axes_export).unwrap();
+    let exported: serde_json::Value = serde_json::from_str(
+        &std::fs::read_to_string(path.with_extension("json")).unwrap()).unwrap();
+
+    let layers_info = exported["layers_info"].as_array().unwrap();
+    assert_eq!(layers_info.len(), 4);
+    assert_eq!(layers_info[0]["name"], "cap");
+    assert_eq!(layers_info[1]["lithology"], "Sandstone");
+    assert_eq!(layers_info[2], serde_json::to_value(LayerInfo::new()).unwrap());
+    assert_eq!(layers_info[3]["name"], "intrusion");
+}
+
+#[test]
+fn params_without_new_fields_tests() {
+    // Params written before layers info, faults and history were added
+    let mut value = serde_json::to_value(Params3D::new()).unwrap();
+    let fields = value.as_object_mut().unwrap();
+    for field in ["layers_info", "lithology_property", "fluid_contacts", "lateral_trends", "faults", "listric_faults",
+        "rift_blocks", "strike_slip_faults", "thrusts", "events", "fault_population", "fault_volumes_needed"] {
+        assert!(fields.remove(field).is_some());
+    }
+    let layers_fill = fields["layers_fill"].as_object_mut().unwrap();
+    layers_fill.remove("transition_matrix");
+    layers_fill.remove("thickness_ranges");
+
+    let params: Params3D = serde_json::from_value(value).unwrap();
+    assert!(params.faults().is_empty());
+    assert!(!params.fault_volumes_needed());
+}
@@ -4,3 +4,9 @@ pub mod axis_test;
 pub mod default_layers_dist_test;
 pub mod border_3d_test;
 pub mod model_fill_test;
+pub mod faults_test;
+pub mod history_test;
+pub mod model_2d_test;
+pub mod resample_test;
+pub mod query_test;
+pub mod lithology_test;
@@ -0,0 +1,187 @@
+use super::types::{Axis, LayersDist, Interpolation};
+use super::types::shifts::{Shift2D, ShiftTypes2D};
+use super::types::generation_params::{Params2D, Params3D, Provenance2D};
+use super::model2d::generate_model;
+use super::model3d::{Model3D, generate_model as generate_3d_model};
+
+#[test]
+fn generate_2d_model_tests() {
+    let mut params = Params2D::new();
+    params.set_x_axis(Axis::generate_axis(0.0, 10.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![10, 10, 10]).unwrap());
+
+    let model = generate_model(params.clone()).unwrap();
+    assert_eq!(model.borders().len(), 10);
+    assert_eq!(model.borders()[4], vec![10, 20, 30]);
+    assert_eq!(model.model().len(), 10);
+    assert_eq!(model.model_mask()[0].len(), 30);
+    assert_eq!(model.model_mask()[0][15], 1);
+
+    let mut graben = Shift2D::new();
+    graben.set_shift_type(ShiftTypes2D::Graber);
+    graben.set_angle(89.9).unwrap();
+    graben.set_shift_force(4);
+    params.add_shift(graben);
+
+    // Dipping graben: block between faults sinks as a whole and keeps flat bottom
+    let mut dipping_params = params.clone();
+    let mut dipping_graben = Shift2D::new();
+    dipping_graben.set_shift_type(ShiftTypes2D::Graber);
+    dipping_graben.set_angle(60.0).unwrap();
+    dipping_graben.set_shift_force(4);
+    dipping_params.add_shift(dipping_graben);
+
+    let model = generate_model(dipping_params).unwrap();
+    let top_border: Vec<i32> = model.borders().iter().map(|x_borders| x_borders[0]).collect();
+    let base_border: Vec<i32> = model.borders().iter().map(|x_borders| x_borders[2]).collect();
+    assert_eq!(top_border, vec![10, 10, 10, 14, 14, 14, 14, 10, 10, 10]);
+    assert_eq!(base_border, vec![30, 30, 30, 34, 34, 34, 34, 30, 30, 30]);
+
+    let mut descent = Shift2D::new();
+    descent.set_pos_x(2.0);
+    descent.set_angle(89.9).unwrap();
+    descent.set_shift_force(2);
+    params.add_shift(descent);
+
+    let model = generate_model(params.clone()).unwrap();
+    let top_border: Vec<i32> = model.borders().iter().map(|x_borders| x_borders[0]).collect();
+    assert_eq!(top_border, vec![10, 10, 12, 16, 16, 16, 16, 12, 12, 12]);
+    assert_eq!(model.model_mask()[4][35], 2);
+
+    let mut lift = Shift2D::new();
+    lift.set_shift_type(ShiftTypes2D::LeftLift);
+    lift.set_shift_force(-1);
+    params.add_shift(lift);
+    assert!(generate_model(params).is_err());
+
+    // Params saved before generation params were added are still readable
+    let old_params = serde_json::json!({ "x_ax": Axis::generate_axis(0.0, 4.0, None).unwrap() });
+    let restored: Params2D = serde_json::from_value(old_params).unwrap();
+    assert_eq!(restored.x_axis().blocks_count(), 4);
+    assert!(restored.shifts().is_empty());
+    assert!(restored.model_needed() && restored.mask_needed());
+    assert!(matches!(restored.provenance(), Provenance2D::Generated));
+}
+
+#[test]
+fn model_2d_provenance_tests() {
+    let mut params = Params2D::new();
+    params.set_x_axis(Axis::generate_axis(0.0, 4.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![5, 5]).unwrap());
+
+    let model = generate_model(params).unwrap();
+    assert!(matches!(model.params().provenance(), Provenance2D::Generated));
+    assert_eq!(model.layers_filling_values().len(), 2);
+
+    let mut params_3d = Params3D::new();
+    params_3d.set_x_axis(Axis::generate_axis(0.0, 4.0, None).unwrap());
+    params_3d.set_y_axis(Axis::generate_axis(0.0, 4.0, None).unwrap());
+    params_3d.set_layers_dist(LayersDist::create_from_vec(vec![5, 5]).unwrap());
+    let model_3d = generate_3d_model(params_3d).unwrap();
+
+    let slice = model_3d.form_2d_by_nums(vec![0, 1, 2], vec![3, 3, 3]).unwrap();
+    assert_eq!(slice.model().len(), 3);
+    assert_eq!(slice.params().x_axis().blocks_count(), 3);
+    assert!(model_3d.form_2d_by_nums(vec![], vec![]).is_err());
+    assert_eq!(slice.layers_filling_values(), model_3d.layers_filling_values());
+    assert_eq!(slice.params().layers_dist().get_layers_dist(), &vec![5, 5]);
+    match slice.params().provenance() {
+        Provenance2D::Slice3D { line, resolution, .. } => {
+            assert_eq!(*resolution, 3);
+            assert_eq!(line[1], [1.5, 3.5]);
+        },
+        _ => panic!("Slice must keep its source"),
+    }
+
+    let serialized = serde_json::to_string(slice.params()).unwrap();
+    let restored: Params2D = serde_json::from_str(&serialized).unwrap();
+    assert!(matches!(restored.provenance(), Provenance2D::Slice3D { resolution: 3, .. }));
+}
+
+#[test]
+fn section_along_tests() {
+    let mut params = Params3D::<f32>::with_value_type();
+    params.set_x_axis(Axis::generate_axis(0.0, 2.0, None).unwrap());
+    params.set_y_axis(Axis::generate_axis(0.0, 1.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![10, 10]).unwrap());
+
+    let borders = vec![vec![vec![10, 20]], vec![vec![20, 20]]];
+    let model = vec![vec![vec![0.0; 20]], vec![vec![10.0; 20]]];
+    let mut mask = vec![vec![vec![0u8; 20]], vec![vec![0u8; 20]]];
+    mask[1][0][15] = 1;
+    let model_3d = Model3D::new(model, mask, borders, vec![vec![0.0, 10.0]; 2], 20, params);
+
+    let section = model_3d.section_along(&[[0.5, 0.5], [1.5, 0.5]], 0.5, Interpolation::Linear).unwrap();
+    assert_eq!(*section.params().x_axis().axis(), vec![0.0, 0.5, 1.0]);
+    assert_eq!(section.borders()[0], vec![13, 20]);
+    assert_eq!(section.borders()[1], vec![18, 20]);
+    assert_eq!(section.model()[0][0], 2.5);
+    assert_eq!(section.model()[1][0], 7.5);
+    assert_eq!(section.model_mask()[1][15], 1);
+
+    // Polyline with a turn, the last trace is shorter than spacing
+    let section = model_3d.section_along(&[[0.0, 0.0], [1.5, 0.0], [1.5, 0.5]], 0.6, Interpolation::Nearest).unwrap();
+    assert_eq!(section.params().x_axis().blocks_count(), 4);
+    assert_eq!(section.borders()[0][0], 10);
+    assert_eq!(section.borders()[3][0], 20);
+
+    assert!(model_3d.section_along(&[[0.5, 0.5], [2.5, 0.5]], 0.5, Interpolation::Nearest).is_err());
+    assert!(model_3d.section_along(&[[0.5, 0.5]], 0.5, Interpolation::Nearest).is_err());
+    assert!(model_3d.section_along(&[[f32::NAN, 0.5], [1.5, 0.5]], 0.5, Interpolation::Nearest).is_err());
+    assert!(model_3d.section_along(&[[0.5, 0.5], [1.5, 0.5]], f32::NAN, Interpolation::Nearest).is_err());
+}
+
+#[test]
+fn axis_slices_tests() {
+    let mut params = Params3D::new();
+    params.set_x_axis(Axis::generate_axis(0.0, 40.0, Some(10.0)).unwrap());
+    params.set_y_axis(Axis::generate_axis(0.0, 3.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![5, 5]).unwrap());
+    let model_3d = generate_3d_model(params).unwrap();
+
+    let inline = model_3d.inline(2).unwrap();
+    assert_eq!(inline.params().x_axis().axis(), model_3d.params().x_axis().axis());
+    assert_eq!(inline.model().len(), 4);
+    assert_eq!(inline.model_mask()[3], model_3d.model_mask()[3][2]);
+
+    let crossline = model_3d.crossline(1).unwrap();
+    assert_eq!(crossline.params().x_axis().axis(), model_3d.params().y_axis().axis());
+    assert_eq!(crossline.model_mask()[2], model_3d.model_mask()[1][2]);
+    assert!(model_3d.crossline(4).is_err());
+
+    let depth_slice = model_3d.depth_slice(7).unwrap();
+    assert_eq!(depth_slice.depth(), 7);
+    assert_eq!(depth_slice.model().len(), 4);
+    assert_eq!(depth_slice.model_mask()[0].len(), 3);
+    assert_eq!(depth_slice.model_mask()[3][1], model_3d.model_mask()[3][1][7]);
+    assert!(model_3d.depth_slice(model_3d.model()[0][0].len()).is_err());
+}
+
+#[test]
+fn extrude_2d_model_tests() {
+    let mut params = Params2D::new();
+    params.set_x_axis(Axis::generate_axis(0.0, 4.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![5, 5]).unwrap());
+    let section = generate_model(params).unwrap();
+
+    let y_axis = Axis::generate_axis(0.0, 3.0, None).unwrap();
+    let model_3d = section.extrude(y_axis.clone(), None).unwrap();
+    assert_eq!(model_3d.borders()[0].len(), 3);
+    assert_eq!(model_3d.borders()[1][2][3], section.borders()[3][1]);
+    assert_eq!(model_3d.model()[1][2], section.model()[1]);
+    assert_eq!(model_3d.model_mask()[3][0], section.model_mask()[3]);
+    assert_eq!(model_3d.params().y_axis().axis(), y_axis.axis());
+
+    let model_3d = section.extrude(y_axis, Some(|_, y_num| y_num as i32)).unwrap();
+    assert_eq!(model_3d.borders()[0][2][0], section.borders()[0][0] + 2);
+    // Base border is moved below the model, so it's clamped to its depth
+    assert_eq!(model_3d.borders()[1][2][0], 10);
+    assert_eq!(model_3d.model_mask()[0][2][6], section.model_mask()[0][4]);
+    assert_eq!(model_3d.model_mask()[0][2][0], section.model_mask()[0][0]);
+
+    // Slice and extrusion of slice keep layers of source model
+    let inline = model_3d.inline(1).unwrap();
+    let extruded = inline.extrude(Axis::generate_axis(0.0, 2.0, None).unwrap(), None).unwrap();
+    assert_eq!(extruded.params().layers_dist().get_layers_count(), 2);
+    assert_eq!(extruded.model_mask()[2][1], model_3d.model_mask()[2][1]);
+}
@@ -78,3 +78,171 @@ fn fill_model_and_mask_tests() {
 
     assert_eq!(errors, 0);
 }
+
+#[test]
+fn float_values_fill_tests() {
+    use crate::types::{Axis, LayersDist, LayersFill};
+    use crate::types::generation_params::Params3D;
+    use crate::model3d::generate_model;
+
+    let mut params = Params3D::<f32>::with_value_type();
+    params.set_x_axis(Axis::generate_axis(0.0, 5.0, None).unwrap());
+    params.set_y_axis(Axis::generate_axis(0.0, 5.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![3, 4, 5]).unwrap());
+
+    let mut fill = LayersFill::<f32>::with_value_type();
+    fill.set_values_preset(vec![vec![0.05, 0.1], vec![0.2], vec![0.3, 0.35]]).unwrap();
+    params.set_layers_fill(fill);
+
+    let model = generate_model(params).unwrap();
+    let fill_values = model.layers_filling_values();
+
+    for (x_num, x_ax) in model.model().iter().enumerate() {
+        for (y_num, y_ax) in x_ax.iter().enumerate() {
+            for (z_num, value) in y_ax.iter().enumerate() {
+                let layer = model.model_mask()[x_num][y_num][z_num] as usize;
+                let values = &fill_values[layer];
+                assert!(values[0] <= *value && *value <= values[values.len() - 1]);
+            }
+        }
+    }
+}
+
+#[test]
+fn float_negative_values_fill_tests() {
+    use crate::types::{Axis, FillValue, LayersDist, LayersFill};
+    use crate::types::generation_params::Params3D;
+    use crate::model3d::generate_model;
+
+    let mut params = Params3D::<f64>::with_value_type();
+    params.set_x_axis(Axis::generate_axis(0.0, 3.0, None).unwrap());
+    params.set_y_axis(Axis::generate_axis(0.0, 3.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![3, 3]).unwrap());
+
+    let mut fill = LayersFill::<f64>::with_value_type();
+    fill.set_values_preset(vec![vec![-2.0], vec![0.5]]).unwrap();
+    fill.set_values_deviation(Some(1.0)).unwrap();
+    params.set_layers_fill(fill);
+
+    let model = generate_model(params).unwrap();
+    assert_eq!(model.layers_filling_values()[0], vec![-3.0, -1.0]);
+    assert_eq!(model.layers_filling_values()[1], vec![0.0, 1.5]);
+
+    let mut result = String::new();
+    f64::NAN.export_value(&mut result);
+    result.push(',');
+    f32::INFINITY.export_value(&mut result);
+    assert_eq!(result, "null,null");
+}
+
+#[test]
+fn markov_presets_sequence_tests() {
+    use crate::types::{LayersDist, LayersFill};
+    use crate::types::generation_params::Params3D;
+    use crate::model3d::fill3d::presets_sequence::*;
+
+    let mut params = Params3D::new();
+    params.set_layers_dist(LayersDist::create_from_vec(vec![5; 12]).unwrap());
+
+    let mut fill = LayersFill::new();
+    fill.set_values_preset(vec![vec![100], vec![200], vec![300]]).unwrap();
+    fill.set_is_preset_odreder(false);
+    assert!(fill.set_transition_matrix(Some(vec![vec![1.0, 0.0]])).is_err());
+    fill.set_transition_matrix(Some(vec![vec![0.0, 1.0, 0.0], vec![0.0, 0.0, 1.0], vec![1.0, 0.0, 0.0]])).unwrap();
+    fill.set_thickness_ranges(Some(vec![[10, 20], [3, 3], [1, 5]])).unwrap();
+    params.set_layers_fill(fill);
+
+    let sequence = create_presets_sequence(&params, 12).unwrap();
+    assert_eq!(sequence.len(), 12);
+    // Chain goes from the deepest layer upwards: 0 -> 1 -> 2 -> 0
+    for i in 0..11 {
+        assert_eq!(sequence[i], (sequence[i + 1] + 1) % 3);
+    }
+
+    let ranges = params.layers_fill().thickness_ranges().clone().unwrap();
+    let layers_dist = layers_dist_from_sequence(&ranges, &sequence).unwrap();
+    for (layer, size) in layers_dist.get_layers_dist().iter().enumerate() {
+        let range = ranges[sequence[layer]];
+        assert!(range[0] <= *size && *size <= range[1]);
+    }
+
+    // Presets changed after transition matrix was set
+    let mut fill = params.layers_fill().clone();
+    fill.set_values_preset(vec![vec![100], vec![200]]).unwrap();
+    params.set_layers_fill(fill);
+    assert!(create_presets_sequence(&params, 12).is_err());
+
+    // Matrix with rows for every preset, but rows are longer than presets count
+    let mut fill_value = serde_json::to_value(params.layers_fill()).unwrap();
+    fill_value["transition_matrix"] = serde_json::json!([[0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+    params.set_layers_fill(serde_json::from_value(fill_value).unwrap());
+    assert!(create_presets_sequence(&params, 12).is_err());
+}
+
+#[test]
+fn fluid_contacts_tests() {
+    use crate::types::{Axis, LayersDist, LayersFill, FluidContact, FluidType, PropertySubstitution};
+    use crate::types::generation_params::Params3D;
+    use crate::model3d::generate_model;
+
+    let mut params = Params3D::new();
+    params.set_x_axis(Axis::generate_axis(0.0, 4.0, None).unwrap());
+    params.set_y_axis(Axis::generate_axis(0.0, 4.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![5, 10, 5]).unwrap());
+
+    let mut fill = LayersFill::new();
+    fill.set_values_preset(vec![vec![100], vec![200], vec![300]]).unwrap();
+    params.set_layers_fill(fill);
+
+    params.add_fluid_contact(FluidContact::new(1, FluidType::Gas, 8, PropertySubstitution::Value(150)).unwrap());
+    params.add_fluid_contact(FluidContact::new(1, FluidType::Oil, 12, PropertySubstitution::Delta(-20)).unwrap());
+    assert!(FluidContact::new(1, FluidType::Water, -1, PropertySubstitution::Value(0)).is_err());
+
+    let model = generate_model(params).unwrap();
+    let column = &model.model()[0][0];
+    let fluid_column = &model.fluid_mask()[0][0];
+
+    assert_eq!(column[..5], [100; 5]);
+    assert_eq!(column[5..8], [150; 3]);
+    assert_eq!(column[8..12], [180; 4]);
+    assert_eq!(column[12..15], [200; 3]);
+    assert_eq!(fluid_column[..5], [0; 5]);
+    assert_eq!(fluid_column[5..8], [1; 3]);
+    assert_eq!(fluid_column[8..12], [2; 4]);
+    assert_eq!(fluid_column[12..15], [3; 3]);
+    assert_eq!(fluid_column[15..], [0; 5]);
+}
+
+#[test]
+fn lateral_trends_tests() {
+    use crate::types::{Axis, LayersDist, LayersFill, LateralTrend};
+    use crate::types::generation_params::Params3D;
+    use crate::model3d::generate_model;
+
+    let mut params = Params3D::new();
+    params.set_x_axis(Axis::generate_axis(0.0, 4.0, None).unwrap());
+    params.set_y_axis(Axis::generate_axis(0.0, 2.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![2, 2]).unwrap());
+
+    let mut fill = LayersFill::new();
+    fill.set_values_preset(vec![vec![100], vec![200]]).unwrap();
+    params.set_layers_fill(fill);
+
+    params.set_lateral_trend(0, Some(LateralTrend::Linear { azimuth: 90.0, gradient: 10.0 }));
+    params.set_lateral_trend(1, Some(LateralTrend::Map(vec![vec![1.0, 2.0, 3.0, 4.0], vec![5.0, 6.0, 7.0, 8.0]])));
+
+    let model = generate_model(params.clone()).unwrap();
+    let first_layer: Vec<i32> = (0..4).map(|x| model.model()[x][0][0]).collect();
+    let second_layer: Vec<i32> = (0..4).map(|x| model.model()[x][1][3]).collect();
+    assert_eq!(first_layer, vec![85, 95, 105, 115]);
+    assert_eq!(second_layer, vec![205, 206, 207, 208]);
+
+    // Trend of layer which doesn't exist is ignored
+    let mut extra_params = params.clone();
+    extra_params.set_lateral_trend(5, Some(LateralTrend::Linear { azimuth: 0.0, gradient: 1.0 }));
+    let extra_model = generate_model(extra_params).unwrap();
+    assert_eq!(extra_model.model(), model.model());
+
+    params.set_lateral_trend(1, Some(LateralTrend::Map(vec![vec![1.0]])));
+    assert!(generate_model(params).is_err());
+}
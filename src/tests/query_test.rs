@@ -0,0 +1,39 @@
+use super::types::{Axis, Interpolation, LayersDist};
+use super::types::generation_params::Params3D;
+use super::model3d::Model3D;
+
+#[test]
+fn point_query_tests() {
+    let mut params = Params3D::<f32>::with_value_type();
+    params.set_x_axis(Axis::generate_axis(0.0, 20.0, Some(10.0)).unwrap());
+    params.set_y_axis(Axis::generate_axis(0.0, 2.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![2, 2]).unwrap());
+
+    // Value is x_num * 10 + z_num, the second column along x is 2 samples deeper
+    let model = (0..2).map(|x_num| vec![(0..4).map(|z_num| (x_num * 10 + z_num) as f32).collect(); 2]).collect();
+    let mask = vec![vec![vec![0, 0, 1, 1]; 2], vec![vec![0, 0, 0, 0]; 2]];
+    let borders = vec![vec![vec![2, 4]; 2], vec![vec![4, 4]; 2]];
+    let model = Model3D::new(model, mask, borders, vec![vec![0.0, 10.0]; 2], 4, params);
+
+    assert_eq!(model.value_at(12.0, 0.5, 2.2, Interpolation::Nearest).unwrap(), 12.0);
+    assert_eq!(model.value_at(10.0, 0.5, 0.5, Interpolation::Linear).unwrap(), 5.0);
+    assert_eq!(model.value_at(10.0, 1.0, 1.0, Interpolation::Linear).unwrap(), 5.5);
+    assert!(model.value_at(21.0, 0.5, 0.5, Interpolation::Nearest).is_err());
+    assert!(model.value_at(5.0, 0.5, 4.5, Interpolation::Nearest).is_err());
+
+    assert_eq!(model.layer_at(5.0, 1.5, 3.0).unwrap(), 1);
+    assert_eq!(model.layer_at(15.0, 1.5, 3.0).unwrap(), 0);
+
+    assert_eq!(model.horizon_depth_at(0, 10.0, 1.0).unwrap(), 3.0);
+    assert_eq!(model.horizon_depth_at(0, 2.0, 1.0).unwrap(), 2.0);
+    assert!(model.horizon_depth_at(2, 10.0, 1.0).is_err());
+
+    let well_path = [[5.0, 0.5, 0.5], [5.0, 0.5, 1.5], [5.0, 0.5, 2.5]];
+    assert_eq!(model.values_at(&well_path, Interpolation::Nearest).unwrap(), vec![0.0, 1.0, 2.0]);
+    assert_eq!(model.layers_at(&well_path).unwrap(), vec![0, 0, 1]);
+    assert_eq!(model.horizon_depths_at(1, &[[5.0, 0.5], [15.0, 0.5]]).unwrap(), vec![4.0, 4.0]);
+
+    assert_eq!(model.get_by_num(1, 1).unwrap(), vec![10.0, 11.0, 12.0, 13.0]);
+    assert!(model.get_by_num(2, 0).is_err());
+    assert!(model.get_by_num(0, 2).is_err());
+}
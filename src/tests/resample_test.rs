@@ -0,0 +1,124 @@
+use super::types::{Axis, AxisRange, LayersDist, ResampleMethod, UpscaleMethod};
+use super::types::generation_params::Params3D;
+use super::model3d::Model3D;
+
+// Model 4x2x8 with two flat layers, value is x_num * 10 + z_num
+fn test_model() -> Model3D<f32> {
+    let mut params = Params3D::<f32>::with_value_type();
+    params.set_x_axis(Axis::generate_axis(0.0, 40.0, Some(10.0)).unwrap());
+    params.set_y_axis(Axis::generate_axis(0.0, 2.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![4, 4]).unwrap());
+
+    let model = (0..4).map(|x_num| vec![(0..8).map(|z_num| (x_num * 10 + z_num) as f32).collect(); 2]).collect();
+    let mask = vec![vec![(0..8).map(|z_num| (z_num / 4) as u8).collect(); 2]; 4];
+    let borders = vec![vec![vec![4; 4]; 2], vec![vec![8; 4]; 2]];
+
+    Model3D::new(model, mask, borders, vec![vec![0.0, 100.0]; 2], 8, params)
+}
+
+#[test]
+fn crop_model_tests() {
+    let model = test_model();
+
+    let cropped = model.crop(
+        AxisRange::World { start: 15.0, end: 30.0 },
+        AxisRange::Index { start: 1, end: 2 },
+        AxisRange::Index { start: 2, end: 6 },
+    ).unwrap();
+    assert_eq!(*cropped.params().x_axis().axis(), vec![10.0, 20.0, 30.0]);
+    assert_eq!(*cropped.params().y_axis().axis(), vec![1.0, 2.0]);
+    assert_eq!(cropped.max_depth(), 4);
+    assert_eq!(cropped.model()[0][0], vec![12.0, 13.0, 14.0, 15.0]);
+    assert_eq!(cropped.model_mask()[1][0], vec![0, 0, 1, 1]);
+    assert_eq!(cropped.borders()[0][0], vec![2, 2]);
+    assert_eq!(cropped.borders()[1][0], vec![4, 4]);
+
+    assert!(model.crop(
+        AxisRange::World { start: 50.0, end: 60.0 },
+        AxisRange::Index { start: 0, end: 2 },
+        AxisRange::Index { start: 0, end: 8 },
+    ).is_err());
+}
+
+#[test]
+fn resample_model_tests() {
+    let model = test_model();
+    let x_axis = Axis::generate_axis(0.0, 40.0, Some(20.0)).unwrap();
+    let y_axis = Axis::generate_axis(0.0, 2.0, None).unwrap();
+    let z_axis = Axis::generate_axis(0.0, 8.0, Some(2.0)).unwrap();
+
+    let averaged = model.resample(x_axis.clone(), y_axis.clone(), z_axis.clone(), ResampleMethod::Average).unwrap();
+    assert_eq!(averaged.model().len(), 2);
+    assert_eq!(averaged.model()[1][0], vec![25.5, 27.5, 29.5, 31.5]);
+    assert_eq!(averaged.model_mask()[0][1], vec![0, 0, 1, 1]);
+    assert_eq!(averaged.borders()[0][0], vec![2, 2]);
+    assert_eq!(averaged.max_depth(), 4);
+
+    let nearest = model.resample(x_axis, y_axis, z_axis, ResampleMethod::Nearest).unwrap();
+    assert_eq!(nearest.model()[1][0][0], 20.0);
+
+    let x_axis = Axis::generate_axis(10.0, 20.0, Some(5.0)).unwrap();
+    let linear = model.resample(x_axis, Axis::generate_axis(0.0, 1.0, None).unwrap(),
+        Axis::generate_axis(0.0, 8.0, None).unwrap(), ResampleMethod::Linear).unwrap();
+    assert_eq!(linear.model()[0][0][0], 7.5);
+    assert_eq!(linear.model()[1][0][0], 12.5);
+
+    // Distance to faults is measured in blocks of resampled model
+    let mut model = test_model();
+    model.set_fault_mask((0..4).map(|x_num| vec![vec![if x_num < 2 { 1 } else { 0 }; 8]; 2]).collect());
+    let resampled = model.resample(Axis::generate_axis(0.0, 40.0, Some(20.0)).unwrap(), Axis::generate_axis(0.0, 2.0, None).unwrap(),
+        Axis::generate_axis(0.0, 8.0, Some(2.0)).unwrap(), ResampleMethod::Nearest).unwrap();
+    assert_eq!(resampled.fault_mask()[0][0], vec![1; 4]);
+    assert_eq!(resampled.fault_distance()[0][0], vec![0.0; 4]);
+    assert_eq!(resampled.fault_distance()[1][1], vec![1.0; 4]);
+}
+
+#[test]
+fn upscale_model_tests() {
+    let model = test_model();
+
+    let (upscaled, stats) = model.upscale([2, 2, 4], UpscaleMethod::Arithmetic).unwrap();
+    assert_eq!(*upscaled.params().x_axis().axis(), vec![0.0, 20.0, 40.0]);
+    assert_eq!(*upscaled.params().y_axis().axis(), vec![0.0, 2.0]);
+    assert_eq!(upscaled.model()[0][0], vec![6.5, 10.5]);
+    assert_eq!(upscaled.model_mask()[1][0], vec![0, 1]);
+    assert_eq!(upscaled.borders()[0][0], vec![1, 1]);
+    assert_eq!(stats.min()[0][0][0], 0.0);
+    assert_eq!(stats.max()[0][0][0], 13.0);
+    assert_eq!(stats.majority_fraction()[0][0][1], 1.0);
+
+    // Cell with half of zeros
+    let (harmonic, _) = model.upscale([2, 1, 1], UpscaleMethod::Harmonic).unwrap();
+    assert_eq!(harmonic.model()[0][0][0], 0.0);
+    let (geometric, _) = model.upscale([1, 1, 2], UpscaleMethod::Geometric).unwrap();
+    assert!((geometric.model()[1][0][0] - (10.0f32 * 11.0).sqrt()).abs() < 1e-4);
+
+    // Backus averages columns harmonically and then arithmetically
+    let (backus, _) = model.upscale([2, 1, 2], UpscaleMethod::Backus).unwrap();
+    let column_average = |first: f32, second: f32| 2.0 / (1.0 / first + 1.0 / second);
+    assert!((backus.model()[1][0][0] - (column_average(20.0, 21.0) + column_average(30.0, 31.0)) / 2.0).abs() < 1e-4);
+
+    let (odd, _) = model.upscale([3, 2, 3], UpscaleMethod::Arithmetic).unwrap();
+    assert_eq!(*odd.params().x_axis().axis(), vec![0.0, 30.0, 40.0]);
+    assert_eq!(odd.max_depth(), 3);
+    assert_eq!(odd.model_mask()[0][0], vec![0, 1, 1]);
+    assert_eq!(odd.borders()[0][0][0], 1);
+
+    // Borders are taken from coarse mask, averaged border (depth 2) would disagree with it
+    let mut params = Params3D::<f32>::with_value_type();
+    params.set_x_axis(Axis::generate_axis(0.0, 2.0, None).unwrap());
+    params.set_y_axis(Axis::generate_axis(0.0, 1.0, None).unwrap());
+    params.set_layers_dist(LayersDist::create_from_vec(vec![4, 4]).unwrap());
+    let mask = vec![
+        vec![(0..8).map(|z_num| (z_num >= 2) as u8).collect()],
+        vec![(0..8).map(|z_num| (z_num >= 6) as u8).collect()],
+    ];
+    let borders = vec![vec![vec![2, 6]], vec![vec![8, 8]]];
+    let uneven = Model3D::new(Vec::new(), mask, borders, vec![vec![0.0, 100.0]; 2], 8, params);
+    let (upscaled, _) = uneven.upscale([2, 1, 2], UpscaleMethod::Arithmetic).unwrap();
+    assert_eq!(upscaled.model_mask()[0][0], vec![0, 0, 0, 1]);
+    assert_eq!(upscaled.borders()[0][0][0], 3);
+    assert_eq!(upscaled.borders()[1][0][0], 4);
+
+    assert!(model.upscale([0, 1, 1], UpscaleMethod::Arithmetic).is_err());
+}
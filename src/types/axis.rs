@@ -134,10 +134,6 @@ impl Axis {
 
 // TODO: Recreate this method or remove
 impl Axis {
-    pub fn find_element_smaller(&self, _target: f32) -> Option<usize> {
-        Some(5)
-    }
-
     pub fn convert_to_perp_ax(_pos: f32, angle: f32) {
         let _new_angle = ((180.0 - angle) * 1000.0).round() / 1000.0;
 
@@ -218,3 +214,47 @@ impl Axis {
         &self.centers
     }
 }
+
+impl Axis {
+    /// Returns index of block which contains coord (coord on edge belongs to the next block, the end
+    /// of axis belongs to the last one), None if coord is outside axis or isn't finite
+    pub fn find_element_smaller(&self, target: f32) -> Option<usize> {
+        if !target.is_finite() || target < self.axis[0] || target > self.axis[self.axis.len() - 1] {
+            return None
+        }
+
+        let index = self.axis.partition_point(|edge| *edge <= target);
+        Some((index - 1).min(self.blocks_count - 1))
+    }
+
+    /// Returns two neighbour blocks around coord and part of distance between their centers,
+    /// coords outside centers are clamped to the nearest block
+    pub fn linear_neighbours(&self, coord: f32) -> (usize, usize, f32) {
+        let index = self.centers.partition_point(|center| *center <= coord);
+
+        if index == 0 {
+            (0, 0, 0.0)
+        } else if index >= self.centers.len() {
+            (self.centers.len() - 1, self.centers.len() - 1, 0.0)
+        } else {
+            let part = (coord - self.centers[index - 1]) / (self.centers[index] - self.centers[index - 1]);
+            (index - 1, index, part)
+        }
+    }
+
+    /// Returns index of block which center is the nearest to coord, coords outside axis are clamped
+    pub fn nearest_block(&self, coord: f32) -> usize {
+        match self.centers.binary_search_by(|center| center.total_cmp(&coord)) {
+            Ok(index) => index,
+            Err(0) => 0,
+            Err(index) if index >= self.centers.len() => self.centers.len() - 1,
+            Err(index) => {
+                if coord - self.centers[index - 1] <= self.centers[index] - coord {
+                    index - 1
+                } else {
+                    index
+                }
+            }
+        }
+    }
+}
@@ -164,6 +164,7 @@ pub fn generate_layers_dist_vec(
     }
 
     // function params are named by LayersDist's first letters, e.g. ln - (l)ayers_(n)um
+    #[allow(clippy::unnecessary_unwrap)]
     fn validate_params(ln: u8, min_ls: i32, max_ls: i32, ls: Option<i32>) -> Result<(), &'static str> {
         if min_ls > max_ls {
             return Err("Max layer's size must be bigger than min layer's size")
@@ -0,0 +1,92 @@
+use numtoa::NumToA;
+
+use crate::types::FillValue;
+
+impl FillValue for i32 {
+    fn from_f64(value: f64) -> i32 {
+        value.round() as i32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn deviation(dev_cof: f32, model_size: i32) -> i32 {
+        if dev_cof < 1.0 {
+            (dev_cof * model_size as f32) as i32
+        } else {
+            dev_cof as i32
+        }
+    }
+
+    fn export_value(self, result: &mut String) {
+        let mut buf = [0u8; 12];
+        result.push_str(self.numtoa_str(10, &mut buf));
+    }
+}
+
+impl FillValue for f32 {
+    fn from_f64(value: f64) -> f32 {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn deviation(dev_cof: f32, _model_size: i32) -> f32 {
+        dev_cof
+    }
+
+    fn export_value(self, result: &mut String) {
+        // NaN and infinity are not valid JSON numbers
+        if self.is_finite() {
+            *result += &self.to_string();
+        } else {
+            *result += "null";
+        }
+    }
+}
+
+impl FillValue for f64 {
+    fn from_f64(value: f64) -> f64 {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn deviation(dev_cof: f32, _model_size: i32) -> f64 {
+        dev_cof as f64
+    }
+
+    fn export_value(self, result: &mut String) {
+        // NaN and infinity are not valid JSON numbers
+        if self.is_finite() {
+            *result += &self.to_string();
+        } else {
+            *result += "null";
+        }
+    }
+}
+
+/// Pushes filling values of layers to JSON string as array of arrays
+pub(crate) fn export_fill_values<T: FillValue>(result: &mut String, fill_values: &[Vec<T>]) {
+    *result += "[";
+    for (layer_num, values) in fill_values.iter().enumerate() {
+        *result += "[";
+        for (value_num, value) in values.iter().enumerate() {
+            value.export_value(result);
+            if value_num != values.len() - 1 {
+                result.push(',');
+            }
+        }
+        if layer_num != fill_values.len() - 1 {
+            *result += "],";
+        } else {
+            *result += "]";
+        }
+    }
+    *result += "]";
+}
@@ -0,0 +1,75 @@
+use crate::types::{FillValue, FluidContact, FluidType, PropertySubstitution};
+
+impl FluidType {
+    /// Returns value of fluid inside fluid mask, 0 is reserved for cells without fluid
+    pub fn mask_value(&self) -> u8 {
+        match self {
+            FluidType::Gas => 1,
+            FluidType::Oil => 2,
+            FluidType::Water => 3,
+        }
+    }
+}
+
+impl<T: FillValue> PropertySubstitution<T> {
+    /// Returns new value of property
+    pub fn apply(&self, value: T) -> T {
+        match self {
+            PropertySubstitution::Value(new_value) => *new_value,
+            PropertySubstitution::Delta(delta) => T::from_f64(value.to_f64() + delta.to_f64()),
+        }
+    }
+}
+
+impl<T: FillValue> FluidContact<T> {
+    pub fn new(layer: usize, fluid: FluidType, depth: i32, substitution: PropertySubstitution<T>) -> Result<FluidContact<T>, &'static str> {
+        if depth < 0 {
+            return Err("Contact depth cannot be negative")
+        }
+
+        Ok(FluidContact {
+            layer,
+            fluid,
+            depth,
+            substitution,
+        })
+    }
+}
+
+impl<T: FillValue> FluidContact<T> {
+    pub fn set_layer(&mut self, layer: usize) {
+        self.layer = layer
+    }
+
+    pub fn layer(&self) -> usize {
+        self.layer
+    }
+
+    pub fn set_fluid(&mut self, fluid: FluidType) {
+        self.fluid = fluid
+    }
+
+    pub fn fluid(&self) -> FluidType {
+        self.fluid
+    }
+
+    pub fn set_depth(&mut self, depth: i32) -> Result<(), &'static str> {
+        if depth < 0 {
+            return Err("Contact depth cannot be negative")
+        }
+        self.depth = depth;
+        Ok(())
+    }
+
+    pub fn depth(&self) -> i32 {
+        self.depth
+    }
+
+    pub fn set_substitution(&mut self, substitution: PropertySubstitution<T>) {
+        self.substitution = substitution
+    }
+
+    pub fn substitution(&self) -> &PropertySubstitution<T> {
+        &self.substitution
+    }
+}
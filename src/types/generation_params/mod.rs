@@ -1,13 +1,13 @@
 use serde::{Deserialize, Serialize};
 
-use crate::types::{Axis, LayersDist, LayersBorder, LayersFill};
-use crate::types::shifts::Shift3D;
+use crate::types::{Axis, FillValue, LayersDist, LayersBorder, LayersFill, LayerInfo, LithologyProperty, FluidContact, LateralTrend, GeologicalEvent};
+use crate::types::shifts::{Shift2D, Shift3D, Fault3D, ListricFault3D, FaultPopulation, RiftBlock3D, StrikeSlipFault3D};
 
 mod params3d;
 mod params2d;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct Params3D {
+pub struct Params3D<T = i32> {
     // Axes parameters 
     x_ax: Axis,
     y_ax: Axis,
@@ -16,16 +16,81 @@ pub struct Params3D {
     // How to modify layers
     layers_border: LayersBorder,
     // How to fill layers
-    layers_fill: LayersFill,
+    layers_fill: LayersFill<T>,
+    // Names and lithologies of layers, index is the same as layer's index
+    #[serde(default)]
+    layers_info: Vec<LayerInfo>,
+    // If set, layers with lithology are filled with catalogue range of this property
+    #[serde(default)]
+    lithology_property: Option<LithologyProperty>,
+    // Gas, oil and water contacts inside reservoir layers
+    #[serde(default)]
+    fluid_contacts: Vec<FluidContact<T>>,
+    // Lateral trends of values, index is the same as layer's index
+    #[serde(default)]
+    lateral_trends: Vec<Option<LateralTrend>>,
     shifts: Vec<Shift3D>,
+    #[serde(default)]
+    faults: Vec<Fault3D>,
+    #[serde(default)]
+    listric_faults: Vec<ListricFault3D>,
+    #[serde(default)]
+    rift_blocks: Vec<RiftBlock3D>,
+    #[serde(default)]
+    strike_slip_faults: Vec<StrikeSlipFault3D>,
+    // Faults which can repeat layers, they are applied to layer intervals
+    #[serde(default)]
+    thrusts: Vec<Fault3D>,
+    // Ordered geological history, applied before shifts and faults above, which act like events
+    // at the end of history in fixed order of their lists
+    #[serde(default)]
+    events: Vec<GeologicalEvent<T>>,
+    // Random faults which are added to planar faults during generation
+    #[serde(default)]
+    fault_population: Option<FaultPopulation>,
     // Optional params to reduce generation time
     model_needed: bool,
     mask_needed: bool,
+    #[serde(default)]
+    fault_volumes_needed: bool,
     // Depth scaling
     depth_scale: f32,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct Params2D {
+#[serde(bound(deserialize = "T: FillValue"))]
+pub struct Params2D<T = i32> {
     x_ax: Axis,
+    // Params of 2D generation, see `model2d::generate_model`
+    #[serde(default)]
+    layers_dist: LayersDist,
+    #[serde(default)]
+    layers_border: LayersBorder,
+    #[serde(default)]
+    layers_fill: LayersFill<T>,
+    #[serde(default)]
+    shifts: Vec<Shift2D>,
+    // Params saved without these flags are loaded with model and mask needed, like `Params2D::new`
+    #[serde(default = "needed_by_default")]
+    model_needed: bool,
+    #[serde(default = "needed_by_default")]
+    mask_needed: bool,
+    #[serde(default)]
+    provenance: Provenance2D<T>,
+}
+
+fn needed_by_default() -> bool {
+    true
+}
+
+/// Source of 2D model
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(bound(deserialize = "T: FillValue"))]
+pub enum Provenance2D<T = i32> {
+    /// Model is generated from 2D generation params of `Params2D`
+    #[default]
+    Generated,
+    /// Model is a slice of 3D model: params of source model, points (x, y) of slice line in source
+    /// model's axis units and number of traces
+    Slice3D { source_params: Box<Params3D<T>>, line: Vec<[f32; 2]>, resolution: usize },
 }
@@ -1,21 +1,36 @@
-use crate::types::generation_params::Params2D;
-use crate::types::Axis;
+use crate::types::{Axis, FillValue, LayersDist, LayersBorder, LayersFill};
+use crate::types::shifts::Shift2D;
+use crate::types::generation_params::{Params2D, Provenance2D};
 
-impl Default for Params2D {
+impl<T: FillValue> Default for Params2D<T> {
     fn default() -> Self {
-        Self::new()
+        Self::with_value_type()
     }
 }
 
 impl Params2D {
     pub fn new() -> Params2D {
+        Params2D::with_value_type()
+    }
+}
+
+impl<T: FillValue> Params2D<T> {
+    /// Creates default params for models with values of type T, e.g. `Params2D::<f32>::with_value_type()`
+    pub fn with_value_type() -> Params2D<T> {
         Params2D {
             x_ax: Axis::new(),
+            layers_dist: LayersDist::new(),
+            layers_border: LayersBorder::new(),
+            layers_fill: LayersFill::with_value_type(),
+            shifts: Vec::new(),
+            model_needed: true,
+            mask_needed: true,
+            provenance: Provenance2D::Generated,
         }
     }
 }
 
-impl Params2D {
+impl<T: FillValue> Params2D<T> {
     pub fn set_x_axis(&mut self, axis: Axis) {
         self.x_ax = axis;
     }
@@ -23,4 +38,60 @@ impl Params2D {
     pub fn x_axis(&self) -> &Axis {
         &self.x_ax
     }
+
+    pub fn set_layers_dist(&mut self, layers_dist: LayersDist) {
+        self.layers_dist = layers_dist;
+    }
+
+    pub fn layers_dist(&self) -> &LayersDist {
+        &self.layers_dist
+    }
+
+    pub fn set_layers_border(&mut self, layers_border: LayersBorder) {
+        self.layers_border = layers_border;
+    }
+
+    pub fn layers_border(&self) -> &LayersBorder {
+        &self.layers_border
+    }
+
+    pub fn set_layers_fill(&mut self, layers_fill: LayersFill<T>) {
+        self.layers_fill = layers_fill;
+    }
+
+    pub fn layers_fill(&self) -> &LayersFill<T> {
+        &self.layers_fill
+    }
+
+    pub fn add_shift(&mut self, shift: Shift2D) {
+        self.shifts.push(shift)
+    }
+
+    pub fn shifts(&self) -> &Vec<Shift2D> {
+        &self.shifts
+    }
+
+    pub fn set_model_needed(&mut self, is_full_model: bool) {
+        self.model_needed = is_full_model;
+    }
+
+    pub fn model_needed(&self) -> bool {
+        self.model_needed
+    }
+
+    pub fn set_mask_needed(&mut self, is_mask: bool) {
+        self.mask_needed = is_mask;
+    }
+
+    pub fn mask_needed(&self) -> bool {
+        self.mask_needed
+    }
+
+    pub fn set_provenance(&mut self, provenance: Provenance2D<T>) {
+        self.provenance = provenance
+    }
+
+    pub fn provenance(&self) -> &Provenance2D<T> {
+        &self.provenance
+    }
 }
@@ -1,30 +1,49 @@
-use crate::types::{Axis, LayersDist, LayersBorder, LayersFill};
-use crate::types::shifts::Shift3D;
+use crate::types::{Axis, FillValue, LayersDist, LayersBorder, LayersFill, LayerInfo, Lithology, LithologyProperty, FluidContact, LateralTrend, GeologicalEvent};
+use crate::types::shifts::{Shift3D, Fault3D, ListricFault3D, FaultPopulation, RiftBlock3D, StrikeSlipFault3D};
 use crate::types::generation_params::Params3D;
 
-impl Default for Params3D {
+impl<T: FillValue> Default for Params3D<T> {
     fn default() -> Self {
-        Self::new()
+        Self::with_value_type()
     }
 }
 
 impl Params3D {
     pub fn new() -> Params3D {
+        Params3D::with_value_type()
+    }
+}
+
+impl<T: FillValue> Params3D<T> {
+    /// Creates default params for models with values of type T, e.g. `Params3D::<f32>::with_value_type()`
+    pub fn with_value_type() -> Params3D<T> {
         Params3D {
             x_ax: Axis::new(),
             y_ax: Axis::new(),
             layers_dist: LayersDist::new(),
             layers_border: LayersBorder::new(),
-            layers_fill: LayersFill::new(),
+            layers_fill: LayersFill::with_value_type(),
+            layers_info: Vec::new(),
+            lithology_property: None,
+            fluid_contacts: Vec::new(),
+            lateral_trends: Vec::new(),
             shifts: Vec::new(),
+            faults: Vec::new(),
+            listric_faults: Vec::new(),
+            rift_blocks: Vec::new(),
+            strike_slip_faults: Vec::new(),
+            thrusts: Vec::new(),
+            events: Vec::new(),
+            fault_population: None,
             model_needed: true,
             mask_needed: true,
+            fault_volumes_needed: false,
             depth_scale: 1.0,
         }
     }
 }
 
-impl Params3D {
+impl<T: FillValue> Params3D<T> {
     pub fn set_x_axis(&mut self, axis: Axis) {
         self.x_ax = axis;
     }
@@ -57,14 +76,73 @@ impl Params3D {
         &self.layers_border
     }
 
-    pub fn set_layers_fill(&mut self, layers_fill: LayersFill) {
+    pub fn set_layers_fill(&mut self, layers_fill: LayersFill<T>) {
         self.layers_fill = layers_fill
     }
 
-    pub fn layers_fill(&self) -> &LayersFill {
+    pub fn layers_fill(&self) -> &LayersFill<T> {
         &self.layers_fill
     }
 
+    pub fn set_layers_info(&mut self, layers_info: Vec<LayerInfo>) {
+        self.layers_info = layers_info
+    }
+
+    pub fn layers_info(&self) -> &Vec<LayerInfo> {
+        &self.layers_info
+    }
+
+    /// Returns info for layer, None if layer was not described
+    pub fn layer_info(&self, layer: usize) -> Option<&LayerInfo> {
+        self.layers_info.get(layer)
+    }
+
+    pub fn set_layer_name(&mut self, layer: usize, name: &str) {
+        self.layer_info_mut(layer).set_name(Some(name.to_string()))
+    }
+
+    pub fn set_layer_lithology(&mut self, layer: usize, lithology: Lithology) {
+        self.layer_info_mut(layer).set_lithology(Some(lithology))
+    }
+
+    fn layer_info_mut(&mut self, layer: usize) -> &mut LayerInfo {
+        if self.layers_info.len() <= layer {
+            self.layers_info.resize(layer + 1, LayerInfo::new());
+        }
+        &mut self.layers_info[layer]
+    }
+
+    /// Layers with lithology are filled with catalogue range of this property instead of values_preset.
+    /// Note: Integer models round catalogue values, so density and porosity need f32 or f64 models
+    pub fn set_lithology_property(&mut self, property: Option<LithologyProperty>) {
+        self.lithology_property = property
+    }
+
+    pub fn lithology_property(&self) -> Option<LithologyProperty> {
+        self.lithology_property
+    }
+
+    pub fn add_fluid_contact(&mut self, contact: FluidContact<T>) {
+        self.fluid_contacts.push(contact)
+    }
+
+    pub fn fluid_contacts(&self) -> &Vec<FluidContact<T>> {
+        &self.fluid_contacts
+    }
+
+    /// Sets lateral trend for layer, trend's value is added on top of values_preset and deviation.
+    /// Trends of layers which don't exist are ignored, cells of intrusions don't get trends
+    pub fn set_lateral_trend(&mut self, layer: usize, trend: Option<LateralTrend>) {
+        if self.lateral_trends.len() <= layer {
+            self.lateral_trends.resize(layer + 1, None);
+        }
+        self.lateral_trends[layer] = trend;
+    }
+
+    pub fn lateral_trends(&self) -> &Vec<Option<LateralTrend>> {
+        &self.lateral_trends
+    }
+
     pub fn add_shift(&mut self, shift: Shift3D) {
         self.shifts.push(shift)
     }
@@ -73,6 +151,75 @@ impl Params3D {
         &self.shifts
     }
 
+    /// Adds planar fault, faults are applied after all shifts
+    pub fn add_fault(&mut self, fault: Fault3D) {
+        self.faults.push(fault)
+    }
+
+    pub fn faults(&self) -> &Vec<Fault3D> {
+        &self.faults
+    }
+
+    /// Adds listric fault, listric faults are applied after planar ones
+    pub fn add_listric_fault(&mut self, fault: ListricFault3D) {
+        self.listric_faults.push(fault)
+    }
+
+    pub fn listric_faults(&self) -> &Vec<ListricFault3D> {
+        &self.listric_faults
+    }
+
+    /// Adds horst or graben, blocks are applied after listric faults
+    pub fn add_rift_block(&mut self, rift_block: RiftBlock3D) {
+        self.rift_blocks.push(rift_block)
+    }
+
+    pub fn rift_blocks(&self) -> &Vec<RiftBlock3D> {
+        &self.rift_blocks
+    }
+
+    /// Adds strike-slip fault, strike-slip faults are applied after rift blocks
+    pub fn add_strike_slip_fault(&mut self, fault: StrikeSlipFault3D) {
+        self.strike_slip_faults.push(fault)
+    }
+
+    pub fn strike_slip_faults(&self) -> &Vec<StrikeSlipFault3D> {
+        &self.strike_slip_faults
+    }
+
+    /// Adds thrust, thrusts are applied last and can repeat layers in a column, so model with thrusts
+    /// is filled from layer intervals (see `Model3D::intervals`)
+    pub fn add_thrust(&mut self, fault: Fault3D) {
+        self.thrusts.push(fault)
+    }
+
+    pub fn thrusts(&self) -> &Vec<Fault3D> {
+        &self.thrusts
+    }
+
+    /// Adds event to geological history. History is applied right after borders generation, layers
+    /// which are not deposited by history are deposited at its end.
+    /// Shifts and faults added directly are applied after the whole history to all layers in fixed
+    /// order: shifts, planar faults, listric faults, rift blocks, strike-slip faults and thrusts, so
+    /// they act like events at the end of history. Use history when order of deformations matters
+    pub fn add_event(&mut self, event: GeologicalEvent<T>) {
+        self.events.push(event)
+    }
+
+    pub fn events(&self) -> &Vec<GeologicalEvent<T>> {
+        &self.events
+    }
+
+    /// Sets random fault network, generated faults are added to planar faults, so params of model
+    /// contain every generated fault
+    pub fn set_fault_population(&mut self, fault_population: Option<FaultPopulation>) {
+        self.fault_population = fault_population
+    }
+
+    pub fn fault_population(&self) -> &Option<FaultPopulation> {
+        &self.fault_population
+    }
+
     pub fn set_model_needed(&mut self, is_full_model: bool) {
         self.model_needed = is_full_model;
     }
@@ -89,6 +236,15 @@ impl Params3D {
         self.mask_needed
     }
 
+    /// If set, model also contains fault mask, distance to faults and fault blocks volumes
+    pub fn set_fault_volumes_needed(&mut self, is_fault_volumes: bool) {
+        self.fault_volumes_needed = is_fault_volumes;
+    }
+
+    pub fn fault_volumes_needed(&self) -> bool {
+        self.fault_volumes_needed
+    }
+
     pub fn set_depth_scale(&mut self, depth_scale: f32) {
         self.depth_scale = depth_scale
     }
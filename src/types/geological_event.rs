@@ -0,0 +1,113 @@
+use crate::types::{Fold3D, Intrusion, FillValue};
+
+impl Default for Fold3D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fold3D {
+    pub fn new() -> Fold3D {
+        Fold3D {
+            azimuth: 90.0,
+            wavelength: 10.0,
+            amplitude: 5.0,
+        }
+    }
+}
+
+impl Fold3D {
+    pub fn set_azimuth(&mut self, azimuth: f32) -> Result<(), &'static str> {
+        if !(0.0..360.0).contains(&azimuth) {
+            return Err("Azimuth should be between 0.0 and 360.0")
+        }
+        self.azimuth = azimuth;
+        Ok(())
+    }
+
+    pub fn azimuth(&self) -> f32 {
+        self.azimuth
+    }
+
+    pub fn set_wavelength(&mut self, wavelength: f32) -> Result<(), &'static str> {
+        if wavelength <= 0.0 {
+            return Err("Wavelength must be bigger than zero")
+        }
+        self.wavelength = wavelength;
+        Ok(())
+    }
+
+    pub fn wavelength(&self) -> f32 {
+        self.wavelength
+    }
+
+    /// Sets amplitude of fold, positive values make syncline at zero phase
+    pub fn set_amplitude(&mut self, amplitude: f32) {
+        self.amplitude = amplitude
+    }
+
+    pub fn amplitude(&self) -> f32 {
+        self.amplitude
+    }
+
+    /// Vertical offset of borders at the point
+    pub fn offset(&self, x: f32, y: f32) -> f32 {
+        let azimuth = self.azimuth.to_radians();
+        let distance = x * azimuth.sin() + y * azimuth.cos();
+        self.amplitude * (2.0 * std::f32::consts::PI * distance / self.wavelength).sin()
+    }
+}
+
+impl<T: FillValue> Intrusion<T> {
+    /// Creates intrusion with center (x, y, z) and radiuses along the same axes
+    pub fn new(center: [f32; 3], radius: [f32; 3], value: T) -> Result<Intrusion<T>, &'static str> {
+        let mut intrusion = Intrusion { center, radius: [1.0; 3], value };
+        intrusion.set_radius(radius)?;
+        Ok(intrusion)
+    }
+
+    pub fn set_center(&mut self, center: [f32; 3]) {
+        self.center = center
+    }
+
+    pub fn center(&self) -> [f32; 3] {
+        self.center
+    }
+
+    pub fn set_radius(&mut self, radius: [f32; 3]) -> Result<(), &'static str> {
+        if radius.iter().any(|value| *value <= 0.0) {
+            return Err("Radiuses of intrusion must be bigger than zero")
+        }
+        self.radius = radius;
+        Ok(())
+    }
+
+    pub fn radius(&self) -> [f32; 3] {
+        self.radius
+    }
+
+    pub fn set_value(&mut self, value: T) {
+        self.value = value
+    }
+
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    pub fn contains(&self, x: f32, y: f32, z: f32) -> bool {
+        [x, y, z].iter().zip(self.center).zip(self.radius)
+            .map(|((coord, center), radius)| ((coord - center) / radius).powi(2))
+            .sum::<f32>() <= 1.0
+    }
+
+    /// Returns range [top, base) of depth cells inside intrusion for column, None if column misses it
+    pub fn column_cells(&self, x: f32, y: f32) -> Option<(i32, i32)> {
+        let rest = 1.0 - ((x - self.center[0]) / self.radius[0]).powi(2) - ((y - self.center[1]) / self.radius[1]).powi(2);
+        if rest < 0.0 {
+            return None
+        }
+        let half_height = self.radius[2] * rest.sqrt();
+        let (top, base) = ((self.center[2] - half_height).ceil() as i32, (self.center[2] + half_height).floor() as i32 + 1);
+        (top < base).then_some((top, base))
+    }
+}
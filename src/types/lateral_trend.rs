@@ -0,0 +1,36 @@
+use crate::types::{Axis, LateralTrend};
+
+impl LateralTrend {
+    /// Calculates trend's offsets for centers of blocks, result is stored as Y->X
+    pub fn offsets(&self, x_ax: &Axis, y_ax: &Axis) -> Result<Vec<Vec<f64>>, &'static str> {
+        let x_centers = x_ax.centers();
+        let y_centers = y_ax.centers();
+
+        match self {
+            LateralTrend::Linear { azimuth, gradient } => {
+                let (dir_x, dir_y) = (azimuth.to_radians().sin() as f64, azimuth.to_radians().cos() as f64);
+                let center_x = ((x_ax.start() + x_ax.end()) / 2.0) as f64;
+                let center_y = ((y_ax.start() + y_ax.end()) / 2.0) as f64;
+
+                Ok(y_centers.iter().map(|y| {
+                    x_centers.iter().map(|x| {
+                        ((*x as f64 - center_x) * dir_x + (*y as f64 - center_y) * dir_y) * *gradient as f64
+                    }).collect()
+                }).collect())
+            },
+            LateralTrend::Radial { center_x, center_y, gradient } => {
+                Ok(y_centers.iter().map(|y| {
+                    x_centers.iter().map(|x| {
+                        ((x - center_x).powi(2) + (y - center_y).powi(2)).sqrt() as f64 * *gradient as f64
+                    }).collect()
+                }).collect())
+            },
+            LateralTrend::Map(map) => {
+                if map.len() != y_centers.len() || map.iter().any(|line| line.len() != x_centers.len()) {
+                    return Err("Trend map must have the same size as x and y axes (Y->X)")
+                }
+                Ok(map.iter().map(|line| line.iter().map(|value| *value as f64).collect()).collect())
+            },
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::types::LayerInterval;
+
+impl LayerInterval {
+    pub fn new(layer: usize, top: i32, base: i32) -> Result<LayerInterval, &'static str> {
+        if base < top {
+            return Err("Base of interval can't be above its top")
+        }
+        Ok(LayerInterval { layer, top, base })
+    }
+
+    pub fn layer(&self) -> usize {
+        self.layer
+    }
+
+    pub fn top(&self) -> i32 {
+        self.top
+    }
+
+    pub fn base(&self) -> i32 {
+        self.base
+    }
+
+    pub fn thickness(&self) -> i32 {
+        self.base - self.top
+    }
+
+    /// Part of interval between depths, None if nothing is left
+    pub fn clip(&self, top: i32, base: i32) -> Option<LayerInterval> {
+        let (new_top, new_base) = (self.top.max(top), self.base.min(base));
+        if new_top >= new_base {
+            return None
+        }
+        Some(LayerInterval { layer: self.layer, top: new_top, base: new_base })
+    }
+
+    /// Interval moved down by offset (up for negative offset)
+    pub fn moved(&self, offset: i32) -> LayerInterval {
+        LayerInterval { layer: self.layer, top: self.top + offset, base: self.base + offset }
+    }
+}
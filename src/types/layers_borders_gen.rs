@@ -62,6 +62,7 @@ impl LayersBorder {
         self.border_max_step
     }
 
+    #[allow(clippy::unnecessary_unwrap)]
     pub fn set_border_step_prob(&mut self, prob: Option<f32>) -> Result<(), &'static str> {
         if prob.is_none() {
             self.border_step_prob = prob
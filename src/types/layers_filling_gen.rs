@@ -1,25 +1,38 @@
-use crate::types::LayersFill;
+use crate::types::{FillValue, LayersFill};
 
-impl Default for LayersFill {
+impl<T: FillValue> Default for LayersFill<T> {
     fn default() -> Self {
-        Self::new()
+        Self::with_value_type()
     }
 }
 
 impl LayersFill {
     pub fn new() -> LayersFill {
+        LayersFill::with_value_type()
+    }
+}
+
+impl<T: FillValue> LayersFill<T> {
+    /// Creates default fill for models with values of type T, e.g. `LayersFill::<f32>::with_value_type()`
+    pub fn with_value_type() -> LayersFill<T> {
         LayersFill {
-            values_preset: vec![vec![100], vec![200], vec![300, 330]],
+            values_preset: vec![
+                vec![T::from_f64(100.0)],
+                vec![T::from_f64(200.0)],
+                vec![T::from_f64(300.0), T::from_f64(330.0)]
+            ],
             is_preset_ordered: true,
             values_deviation: None,
             values_smooth: None,
             values_offset: None,
+            transition_matrix: None,
+            thickness_ranges: None,
         }
     }
 }
 
-impl LayersFill {
-    pub fn set_values_preset(&mut self, values: Vec<Vec<i32>>) -> Result<(), &'static str> {
+impl<T: FillValue> LayersFill<T> {
+    pub fn set_values_preset(&mut self, values: Vec<Vec<T>>) -> Result<(), &'static str> {
         if values.is_empty() { return Err("Vector must contain at least one element")}
         for value in &values {
             if value.len() > 2 { return Err("Every sub vector can contain only 1 or 2 elements")}
@@ -28,7 +41,7 @@ impl LayersFill {
         Ok(())
     }
 
-    pub fn values_preset(&self) -> &Vec<Vec<i32>> {
+    pub fn values_preset(&self) -> &Vec<Vec<T>> {
         &self.values_preset
     }
 
@@ -40,6 +53,8 @@ impl LayersFill {
         self.is_preset_ordered
     }
 
+    /// Sets deviation of one-value presets. For i32 models deviation smaller than 1.0 is part of
+    /// model size and bigger one is absolute value, for f32 and f64 models it's always absolute value
     pub fn set_values_deviation(&mut self, deviation: Option<f32>) -> Result<(), &'static str> {
         if deviation.unwrap_or(1.0) <= 0.0 { return Err("deviation must be positive") };
         self.values_deviation = deviation;
@@ -65,5 +80,52 @@ impl LayersFill {
     pub fn values_offset(&self) -> Option<u32> {
         self.values_offset
     }
-}
 
+    /// Sets Markov chain used when presets are not ordered, `matrix[i][j]` is a probability that
+    /// preset j is deposited right after (above) preset i. Rows are normalized during generation
+    pub fn set_transition_matrix(&mut self, matrix: Option<Vec<Vec<f32>>>) -> Result<(), &'static str> {
+        if let Some(matrix) = &matrix {
+            if matrix.len() != self.values_preset.len() {
+                return Err("Transition matrix must contain row for every values preset")
+            }
+            for row in matrix {
+                if row.len() != self.values_preset.len() {
+                    return Err("Transition matrix must be square")
+                }
+                if row.iter().any(|prob| *prob < 0.0) {
+                    return Err("Transition probabilities cannot be negative")
+                }
+                if row.iter().sum::<f32>() <= 0.0 {
+                    return Err("Every row of transition matrix must contain positive probability")
+                }
+            }
+        }
+        self.transition_matrix = matrix;
+        Ok(())
+    }
+
+    pub fn transition_matrix(&self) -> &Option<Vec<Vec<f32>>> {
+        &self.transition_matrix
+    }
+
+    /// Sets [min, max] size of layers for every preset. If ranges are set, `layers_dist` of params
+    /// is replaced during generation by sizes taken from them (only number of layers is kept)
+    pub fn set_thickness_ranges(&mut self, ranges: Option<Vec<[i32; 2]>>) -> Result<(), &'static str> {
+        if let Some(ranges) = &ranges {
+            if ranges.len() != self.values_preset.len() {
+                return Err("Thickness ranges must contain range for every values preset")
+            }
+            for range in ranges {
+                if range[0] <= 0 || range[1] < range[0] {
+                    return Err("Thickness range must be positive and second element must be bigger or equal first one")
+                }
+            }
+        }
+        self.thickness_ranges = ranges;
+        Ok(())
+    }
+
+    pub fn thickness_ranges(&self) -> &Option<Vec<[i32; 2]>> {
+        &self.thickness_ranges
+    }
+}
@@ -0,0 +1,112 @@
+use crate::types::{Lithology, LithologyProperty, LayerInfo};
+
+impl Lithology {
+    /// Returns all lithologies inside catalogue
+    pub fn all() -> [Lithology; 9] {
+        [
+            Lithology::Sandstone,
+            Lithology::Siltstone,
+            Lithology::Shale,
+            Lithology::Limestone,
+            Lithology::Dolomite,
+            Lithology::Salt,
+            Lithology::Anhydrite,
+            Lithology::Coal,
+            Lithology::Basement,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lithology::Sandstone => "sandstone",
+            Lithology::Siltstone => "siltstone",
+            Lithology::Shale => "shale",
+            Lithology::Limestone => "limestone",
+            Lithology::Dolomite => "dolomite",
+            Lithology::Salt => "salt",
+            Lithology::Anhydrite => "anhydrite",
+            Lithology::Coal => "coal",
+            Lithology::Basement => "basement",
+        }
+    }
+
+    /// Returns default [min, max] range of property for this lithology
+    pub fn default_range(&self, property: LithologyProperty) -> [f64; 2] {
+        match property {
+            LithologyProperty::Velocity => match self {
+                Lithology::Sandstone => [2500.0, 4500.0],
+                Lithology::Siltstone => [2600.0, 4200.0],
+                Lithology::Shale => [2000.0, 4000.0],
+                Lithology::Limestone => [3500.0, 6000.0],
+                Lithology::Dolomite => [4500.0, 7000.0],
+                Lithology::Salt => [4400.0, 4800.0],
+                Lithology::Anhydrite => [5500.0, 6500.0],
+                Lithology::Coal => [1800.0, 2800.0],
+                Lithology::Basement => [5500.0, 6500.0],
+            },
+            LithologyProperty::Density => match self {
+                Lithology::Sandstone => [2.2, 2.6],
+                Lithology::Siltstone => [2.3, 2.6],
+                Lithology::Shale => [2.2, 2.7],
+                Lithology::Limestone => [2.4, 2.7],
+                Lithology::Dolomite => [2.6, 2.85],
+                Lithology::Salt => [2.1, 2.2],
+                Lithology::Anhydrite => [2.9, 3.0],
+                Lithology::Coal => [1.2, 1.5],
+                Lithology::Basement => [2.6, 2.8],
+            },
+            LithologyProperty::Porosity => match self {
+                Lithology::Sandstone => [0.1, 0.3],
+                Lithology::Siltstone => [0.05, 0.2],
+                Lithology::Shale => [0.05, 0.2],
+                Lithology::Limestone => [0.02, 0.2],
+                Lithology::Dolomite => [0.01, 0.15],
+                Lithology::Salt => [0.0, 0.01],
+                Lithology::Anhydrite => [0.0, 0.02],
+                Lithology::Coal => [0.02, 0.1],
+                Lithology::Basement => [0.0, 0.02],
+            },
+        }
+    }
+}
+
+impl LayerInfo {
+    pub fn new() -> LayerInfo {
+        LayerInfo {
+            name: None,
+            lithology: None,
+        }
+    }
+
+    pub fn with_name(name: &str) -> LayerInfo {
+        LayerInfo {
+            name: Some(name.to_string()),
+            lithology: None,
+        }
+    }
+
+    pub fn with_lithology(name: &str, lithology: Lithology) -> LayerInfo {
+        LayerInfo {
+            name: Some(name.to_string()),
+            lithology: Some(lithology),
+        }
+    }
+}
+
+impl LayerInfo {
+    pub fn set_name(&mut self, name: Option<String>) {
+        self.name = name
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+
+    pub fn set_lithology(&mut self, lithology: Option<Lithology>) {
+        self.lithology = lithology
+    }
+
+    pub fn lithology(&self) -> Option<Lithology> {
+        self.lithology
+    }
+}
@@ -1,9 +1,19 @@
 use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use rand::distributions::uniform::SampleUniform;
+
+use crate::types::shifts::{Shift3D, Fault3D, ListricFault3D, RiftBlock3D, StrikeSlipFault3D};
 
 pub mod axis;
 pub mod layers_borders_gen;
 pub mod layers_filling_gen;
 pub mod default_layers_dist;
+pub mod fill_value;
+pub mod lithology;
+pub mod fluid_contact;
+pub mod lateral_trend;
+pub mod geological_event;
+pub mod layer_interval;
 
 pub mod shifts;
 pub mod generation_params;
@@ -16,8 +26,68 @@ pub enum AxisExportType {
     CustomAxis(Vec<f32>),
 }
 
+/// Interpolation of model between columns (block centers)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Interpolation {
+    Nearest,
+    /// Bilinear interpolation between four nearest columns
+    Linear,
+}
+
+/// How values are taken from source model during resampling, mask is always resampled with nearest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ResampleMethod {
+    Nearest,
+    /// Trilinear interpolation between block centers
+    Linear,
+    /// Mean of source blocks which centers are inside new block, nearest block if there are no such blocks
+    Average,
+}
+
+/// How properties are averaged inside coarse cell during upscaling
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum UpscaleMethod {
+    Arithmetic,
+    /// Suits flow across layers (e.g. permeability), zero value makes cell zero
+    Harmonic,
+    /// Suits randomly distributed values (e.g. permeability of heterogeneous rocks)
+    Geometric,
+    /// Backus average for elastic moduli of layered media: harmonic along z, then arithmetic
+    /// between columns of cell. Values must be moduli (e.g. density * velocity^2), velocities
+    /// can't be averaged this way and should be converted to moduli before upscaling
+    Backus,
+}
+
+/// Range of blocks along axis: indexes (end is excluded) or coords in axis units (every block
+/// which intersects range is included), depth is measured in samples
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
+pub enum AxisRange {
+    Index { start: usize, end: usize },
+    World { start: f32, end: f32 },
+}
+
 pub type BorderModFuncParams = fn(usize, usize, usize, i32) -> i32;
 
+/// Depth offset of extruded section for block (x_num, y_num), positive offset moves section down
+pub type ExtrudeVariationFunc = fn(usize, usize) -> i32;
+
+/// Type of values stored inside model: i32 (default), f32 or f64.
+/// Integer values keep fast export path, float values allow properties like porosity or density
+pub trait FillValue:
+    Copy + Default + PartialOrd + std::fmt::Debug + Serialize + DeserializeOwned
+    + SampleUniform<Sampler: Clone + std::fmt::Debug>
+{
+    /// Converts f64 to value type, integers are rounded
+    fn from_f64(value: f64) -> Self;
+    /// Converts value to f64
+    fn to_f64(self) -> f64;
+    /// Calculates absolute deviation from `values_deviation` param, integer types treat values
+    /// smaller than 1.0 as part of model size, float types always use it as absolute value
+    fn deviation(dev_cof: f32, model_size: i32) -> Self;
+    /// Pushes value to JSON string
+    fn export_value(self, result: &mut String);
+}
+
 /// Struct to strore Axis and its params
 /// Note: All the coords inside Axis vec represents edges of blocks, so if vec contains 11 elements
 /// it means there 10 blocks, so then first and latest points are whole model limits.
@@ -61,10 +131,142 @@ pub struct LayersBorder {
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct LayersFill {
-    values_preset: Vec<Vec<i32>>,
+pub struct LayersFill<T = i32> {
+    values_preset: Vec<Vec<T>>,
     is_preset_ordered: bool,
     values_deviation: Option<f32>,
     values_smooth: Option<u32>,
     values_offset: Option<u32>,
+    // Probabilities of transition between presets in depositional order (from the deepest layer upwards)
+    #[serde(default)]
+    transition_matrix: Option<Vec<Vec<f32>>>,
+    // Range of layer's size for every preset
+    #[serde(default)]
+    thickness_ranges: Option<Vec<[i32; 2]>>,
+}
+
+/// Rock types with default ranges of properties
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Lithology {
+    Sandstone,
+    Siltstone,
+    Shale,
+    Limestone,
+    Dolomite,
+    Salt,
+    Anhydrite,
+    Coal,
+    Basement,
+}
+
+/// Property which is taken from lithology catalogue: velocity in m/s, density in g/cc or porosity
+/// as fraction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum LithologyProperty {
+    Velocity,
+    Density,
+    Porosity,
+}
+
+/// Description of one layer, index of layer is the same as value inside model_mask
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct LayerInfo {
+    name: Option<String>,
+    lithology: Option<Lithology>,
+}
+
+/// Fluid inside reservoir layer, value in fluid mask is 1 for gas, 2 for oil and 3 for water.
+/// Cells of reservoir layer below its deepest contact are marked as water without substitution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum FluidType {
+    Gas,
+    Oil,
+    Water,
+}
+
+/// How property of reservoir layer changes above the contact
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum PropertySubstitution<T = i32> {
+    Value(T),
+    Delta(T),
+}
+
+/// Flat contact inside one layer: cells of this layer above contact's depth (and below shallower
+/// contacts of the same layer) are filled with fluid
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FluidContact<T = i32> {
+    layer: usize,
+    fluid: FluidType,
+    depth: i32,
+    substitution: PropertySubstitution<T>,
+}
+
+/// Lateral change of layer's values, trend's value is added to every generated value of the layer
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum LateralTrend {
+    /// Values change by `gradient` per axis unit along azimuth (degrees clockwise from y axis),
+    /// trend is zero in the center of the model
+    Linear { azimuth: f32, gradient: f32 },
+    /// Values change by `gradient` per axis unit with distance from the point
+    Radial { center_x: f32, center_y: f32, gradient: f32 },
+    /// Offset for every block, stored as Y->X like borders
+    Map(Vec<Vec<f32>>),
+}
+
+/// Part of column from top to base depth which belongs to one layer, one layer can have several
+/// intervals in a column (repeated sections)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct LayerInterval {
+    layer: usize,
+    top: i32,
+    base: i32,
+}
+
+/// Sinusoidal folding of borders, fold axes are perpendicular to azimuth (degrees clockwise from y axis).
+/// Wavelength is measured in axis units, amplitude in depth units
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Fold3D {
+    azimuth: f32,
+    wavelength: f32,
+    amplitude: f32,
+}
+
+/// Ellipsoidal intrusive body filled with one value, it replaces host rocks existing at intrusion time
+/// and is moved by later events together with them. Mask value of intrusions is layers count
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Intrusion<T = i32> {
+    center: [f32; 3],
+    radius: [f32; 3],
+    value: T,
+}
+
+/// Surface of erosion, depths are measured in depth units
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum ErosionSurface {
+    Flat { depth: i32 },
+    /// Random surface between depths, generated like random borders with optional maximal step
+    /// between neighbour blocks
+    Random { min_depth: i32, max_depth: i32, max_step: Option<i32> },
+    /// Smooth value noise around depth, wavelength is distance between noise nodes in axis units
+    Noise { depth: i32, amplitude: f32, wavelength: f32 },
+}
+
+/// Step of geological history. Layers are deposited from the deepest one, and every event affects
+/// only layers existing at its time (together with the surface they were deposited on)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum GeologicalEvent<T = i32> {
+    /// Deposits next `layers_count` layers on top of existing ones
+    Deposit { layers_count: usize },
+    Fold(Fold3D),
+    Shift(Shift3D),
+    /// Deposits `layers_count` layers one by one, shift force is split between them and every part
+    /// is applied before deposition of the next layer, so these layers are thicker in moving block
+    GrowthShift { shift: Shift3D, layers_count: usize },
+    Fault(Fault3D),
+    ListricFault(ListricFault3D),
+    RiftBlock(RiftBlock3D),
+    StrikeSlipFault(StrikeSlipFault3D),
+    /// Removes existing rocks above erosion surface, younger layers onlap it
+    Erode(ErosionSurface),
+    Intrude(Intrusion<T>),
 }
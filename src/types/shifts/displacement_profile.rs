@@ -0,0 +1,67 @@
+use crate::types::shifts::DisplacementProfile;
+
+impl DisplacementProfile {
+    /// Returns multiplier of throw for point, distances are measured from the centre of fault
+    pub fn factor(&self, along_strike: f32, down_dip: f32) -> f32 {
+        match self {
+            DisplacementProfile::Constant => 1.0,
+            DisplacementProfile::Elliptical { half_length, half_height } => {
+                let r = DisplacementProfile::normalized_distance(along_strike, down_dip, *half_length, *half_height);
+                (1.0 - r * r).max(0.0)
+            },
+            DisplacementProfile::Custom { half_length, half_height, profile } => {
+                let r = DisplacementProfile::normalized_distance(along_strike, down_dip, *half_length, *half_height);
+                if r >= 1.0 || profile.is_empty() {
+                    return 0.0
+                }
+                if r <= profile[0][0] {
+                    return profile[0][1]
+                }
+
+                for points in profile.windows(2) {
+                    let ([r_start, f_start], [r_end, f_end]) = (points[0], points[1]);
+                    if r <= r_end {
+                        return f_start + (f_end - f_start) * (r - r_start) / (r_end - r_start)
+                    }
+                }
+
+                // Factor goes to zero at the tip line after the last point
+                let [r_last, f_last] = profile[profile.len() - 1];
+                f_last * (1.0 - r) / (1.0 - r_last)
+            },
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), &'static str> {
+        match self {
+            DisplacementProfile::Constant => Ok(()),
+            DisplacementProfile::Elliptical { half_length, half_height } => {
+                if *half_length <= 0.0 || *half_height <= 0.0 {
+                    return Err("Half length and half height of profile must be positive")
+                }
+                Ok(())
+            },
+            DisplacementProfile::Custom { half_length, half_height, profile } => {
+                if *half_length <= 0.0 || *half_height <= 0.0 {
+                    return Err("Half length and half height of profile must be positive")
+                }
+                if profile.is_empty() {
+                    return Err("Profile must contain at least one point")
+                }
+                for points in profile.windows(2) {
+                    if points[1][0] <= points[0][0] {
+                        return Err("Distances inside profile must constantly increase")
+                    }
+                }
+                if profile.iter().any(|point| point[1] < 0.0) {
+                    return Err("Factors inside profile cannot be negative")
+                }
+                Ok(())
+            },
+        }
+    }
+
+    fn normalized_distance(along_strike: f32, down_dip: f32, half_length: f32, half_height: f32) -> f32 {
+        ((along_strike / half_length).powi(2) + (down_dip / half_height).powi(2)).sqrt()
+    }
+}
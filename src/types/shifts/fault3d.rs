@@ -0,0 +1,162 @@
+use crate::types::shifts::{Fault3D, FaultSense, DisplacementProfile};
+
+impl Default for Fault3D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Fault3D {
+    pub fn new() -> Fault3D {
+        Fault3D {
+            pos_x: 5.0,
+            pos_y: 5.0,
+            pos_z: 0.0,
+            strike: 0.0,
+            dip: 60.0,
+            throw: 20,
+            fault_sense: FaultSense::Normal,
+            displacement_profile: DisplacementProfile::Constant,
+        }
+    }
+}
+
+impl Fault3D {
+    pub fn set_pos_x(&mut self, pos_x: f32) {
+        self.pos_x = pos_x
+    }
+
+    pub fn pos_x(&self) -> f32 {
+        self.pos_x
+    }
+
+    pub fn set_pos_y(&mut self, pos_y: f32) {
+        self.pos_y = pos_y
+    }
+
+    pub fn pos_y(&self) -> f32 {
+        self.pos_y
+    }
+
+    /// Sets depth of the point, depth is measured in model's depth units
+    pub fn set_pos_z(&mut self, pos_z: f32) {
+        self.pos_z = pos_z
+    }
+
+    pub fn pos_z(&self) -> f32 {
+        self.pos_z
+    }
+
+    pub fn set_strike(&mut self, strike: f32) -> Result<(), &'static str> {
+        if !(0.0..360.0).contains(&strike) {
+            return Err("Strike should be between 0.0 and 360.0")
+        }
+        self.strike = strike;
+        Ok(())
+    }
+
+    pub fn strike(&self) -> f32 {
+        self.strike
+    }
+
+    pub fn set_dip(&mut self, dip: f32) -> Result<(), &'static str> {
+        if dip <= 0.0 || dip > 90.0 {
+            return Err("Dip should be bigger than 0.0 and not bigger than 90.0")
+        }
+        self.dip = dip;
+        Ok(())
+    }
+
+    pub fn dip(&self) -> f32 {
+        self.dip
+    }
+
+    pub fn set_throw(&mut self, throw: i32) -> Result<(), &'static str> {
+        if throw < 0 {
+            return Err("Throw cannot be negative, use fault sense to set direction")
+        }
+        self.throw = throw;
+        Ok(())
+    }
+
+    pub fn throw(&self) -> i32 {
+        self.throw
+    }
+
+    pub fn set_fault_sense(&mut self, fault_sense: FaultSense) {
+        self.fault_sense = fault_sense
+    }
+
+    pub fn fault_sense(&self) -> FaultSense {
+        self.fault_sense
+    }
+
+    pub fn set_displacement_profile(&mut self, profile: DisplacementProfile) -> Result<(), &'static str> {
+        profile.validate()?;
+        self.displacement_profile = profile;
+        Ok(())
+    }
+
+    pub fn displacement_profile(&self) -> &DisplacementProfile {
+        &self.displacement_profile
+    }
+}
+
+impl Fault3D {
+    /// Returns horizontal unit vector (x, y) pointing in dip direction (towards hanging wall)
+    pub fn dip_direction(&self) -> (f32, f32) {
+        let dip_azimuth = (self.strike + 90.0).to_radians();
+        (dip_azimuth.sin(), dip_azimuth.cos())
+    }
+
+    /// Returns unit vector (x, y) pointing along strike
+    pub fn strike_direction(&self) -> (f32, f32) {
+        let strike = self.strike.to_radians();
+        (strike.sin(), strike.cos())
+    }
+
+    /// Signed distance from point to fault plane, positive values are inside hanging wall
+    pub fn signed_distance(&self, x: f32, y: f32, z: f32) -> f32 {
+        let (dip_x, dip_y) = self.dip_direction();
+        let horizontal = (x - self.pos_x) * dip_x + (y - self.pos_y) * dip_y;
+        let dip = self.dip.to_radians();
+
+        horizontal * dip.sin() - (z - self.pos_z) * dip.cos()
+    }
+
+    /// Depth of fault plane under point, None for vertical faults
+    pub fn plane_depth(&self, x: f32, y: f32) -> Option<f32> {
+        if self.dip >= 90.0 {
+            return None
+        }
+        let (dip_x, dip_y) = self.dip_direction();
+        let horizontal = (x - self.pos_x) * dip_x + (y - self.pos_y) * dip_y;
+
+        Some(self.pos_z + horizontal * self.dip.to_radians().tan())
+    }
+
+    /// Multiplier of throw for point, the centre of fault is its defining point and down dip
+    /// distance is measured along fault plane
+    pub fn throw_factor(&self, x: f32, y: f32, z: f32) -> f32 {
+        let (strike_x, strike_y) = self.strike_direction();
+        let along_strike = (x - self.pos_x) * strike_x + (y - self.pos_y) * strike_y;
+        let down_dip = (z - self.pos_z) / self.dip.to_radians().sin();
+
+        self.displacement_profile.factor(along_strike, down_dip)
+    }
+
+    /// Displacement (x, y, z) of hanging wall for given vertical throw, it goes along fault plane
+    pub fn displacement(&self, throw: f32) -> (f32, f32, f32) {
+        let heave = if self.dip >= 90.0 {
+            0.0
+        } else {
+            throw / self.dip.to_radians().tan()
+        };
+        let (dip_x, dip_y) = self.dip_direction();
+
+        match self.fault_sense {
+            FaultSense::Normal => (heave * dip_x, heave * dip_y, throw),
+            FaultSense::Reverse => (-heave * dip_x, -heave * dip_y, -throw),
+        }
+    }
+}
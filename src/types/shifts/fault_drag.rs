@@ -0,0 +1,73 @@
+use crate::types::shifts::{FaultDrag, DragType};
+
+impl Default for FaultDrag {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FaultDrag {
+    pub fn new() -> FaultDrag {
+        FaultDrag {
+            drag_type: DragType::Normal,
+            width: 2.0,
+            intensity: 0.5,
+        }
+    }
+}
+
+impl FaultDrag {
+    pub fn set_drag_type(&mut self, drag_type: DragType) {
+        self.drag_type = drag_type
+    }
+
+    pub fn drag_type(&self) -> DragType {
+        self.drag_type
+    }
+
+    pub fn set_width(&mut self, width: f32) -> Result<(), &'static str> {
+        if width <= 0.0 {
+            return Err("Drag width must be bigger than zero")
+        }
+        self.width = width;
+        Ok(())
+    }
+
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    pub fn set_intensity(&mut self, intensity: f32) -> Result<(), &'static str> {
+        if !(0.0..=1.0).contains(&intensity) {
+            return Err("Drag intensity must be between 0.0 and 1.0")
+        }
+        self.intensity = intensity;
+        Ok(())
+    }
+
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+}
+
+impl FaultDrag {
+    /// Returns part of shift force taken by drag at given distance from fault
+    pub fn drag_part(&self, distance: f32) -> f32 {
+        if distance >= self.width {
+            return 0.0
+        }
+        let weight = 1.0 - distance.max(0.0) / self.width;
+        self.intensity * weight * weight / 2.0
+    }
+
+    /// Returns shift force for moving side and additional movement (in the same direction as shift)
+    /// for static side at given distance from fault
+    pub fn forces(&self, shift_force: i32, distance: f32) -> (i32, i32) {
+        let drag = shift_force as f32 * self.drag_part(distance);
+        let drag = match self.drag_type {
+            DragType::Normal => drag,
+            DragType::Reverse => -drag,
+        };
+        ((shift_force as f32 - drag).round() as i32, drag.round() as i32)
+    }
+}
@@ -0,0 +1,242 @@
+use rand::Rng;
+use rand::distributions::{Distribution, WeightedIndex};
+
+#[cfg(debug_assertions)]
+use log::trace;
+
+use crate::types::Axis;
+use crate::types::shifts::{DisplacementProfile, Fault3D, FaultPopulation, FaultSense, OrientationSet};
+
+impl Default for OrientationSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OrientationSet {
+    pub fn new() -> OrientationSet {
+        OrientationSet {
+            strike: [0.0, 360.0],
+            dip: [45.0, 75.0],
+            weight: 1.0,
+        }
+    }
+
+    pub fn set_strike(&mut self, strike: [f32; 2]) -> Result<(), &'static str> {
+        if strike.iter().any(|value| !(0.0..=360.0).contains(value)) {
+            return Err("Strike range values should be between 0.0 and 360.0")
+        }
+        self.strike = strike;
+        Ok(())
+    }
+
+    pub fn strike(&self) -> [f32; 2] {
+        self.strike
+    }
+
+    pub fn set_dip(&mut self, dip: [f32; 2]) -> Result<(), &'static str> {
+        if dip[0] > dip[1] {
+            return Err("Second element of dip range must be bigger or equal first one")
+        }
+        if dip[0] <= 0.0 || dip[1] > 90.0 {
+            return Err("Dip should be bigger than 0.0 and not bigger than 90.0")
+        }
+        self.dip = dip;
+        Ok(())
+    }
+
+    pub fn dip(&self) -> [f32; 2] {
+        self.dip
+    }
+
+    /// Sets relative probability of picking this set
+    pub fn set_weight(&mut self, weight: f32) -> Result<(), &'static str> {
+        if weight <= 0.0 {
+            return Err("Weight of orientation set must be bigger than zero")
+        }
+        self.weight = weight;
+        Ok(())
+    }
+
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    fn random_strike<R: Rng>(&self, rng: &mut R) -> f32 {
+        let [start, end] = self.strike;
+        let range = if end >= start { end - start } else { 360.0 - start + end };
+        let strike = start + rng.gen::<f32>() * range;
+        if strike >= 360.0 { strike - 360.0 } else { strike }
+    }
+}
+
+impl Default for FaultPopulation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FaultPopulation {
+    pub fn new() -> FaultPopulation {
+        FaultPopulation {
+            faults_count: Some(5),
+            density: 0.0,
+            orientation_sets: vec![OrientationSet::new()],
+            length_range: [5.0, 50.0],
+            length_exponent: 1.5,
+            throw_coef: 0.1,
+            throw_exponent: 1.0,
+            height_ratio: 0.5,
+            reverse_fraction: 0.0,
+        }
+    }
+}
+
+impl FaultPopulation {
+    pub fn set_faults_count(&mut self, faults_count: Option<u32>) {
+        self.faults_count = faults_count
+    }
+
+    pub fn faults_count(&self) -> Option<u32> {
+        self.faults_count
+    }
+
+    /// Sets number of faults per one square unit of model's area, used only if faults count is None
+    pub fn set_density(&mut self, density: f32) -> Result<(), &'static str> {
+        if density < 0.0 {
+            return Err("Density of faults can't be negative")
+        }
+        self.density = density;
+        Ok(())
+    }
+
+    pub fn density(&self) -> f32 {
+        self.density
+    }
+
+    pub fn set_orientation_sets(&mut self, orientation_sets: Vec<OrientationSet>) -> Result<(), &'static str> {
+        if orientation_sets.is_empty() {
+            return Err("At least one orientation set is needed")
+        }
+        self.orientation_sets = orientation_sets;
+        Ok(())
+    }
+
+    pub fn orientation_sets(&self) -> &Vec<OrientationSet> {
+        &self.orientation_sets
+    }
+
+    /// Sets minimal and maximal length of faults in axis units
+    pub fn set_length_range(&mut self, length_range: [f32; 2]) -> Result<(), &'static str> {
+        if length_range[0] <= 0.0 {
+            return Err("Fault length must be bigger than zero")
+        }
+        if length_range[0] > length_range[1] {
+            return Err("Second element of length range must be bigger or equal first one")
+        }
+        self.length_range = length_range;
+        Ok(())
+    }
+
+    pub fn length_range(&self) -> [f32; 2] {
+        self.length_range
+    }
+
+    pub fn set_length_exponent(&mut self, length_exponent: f32) -> Result<(), &'static str> {
+        if length_exponent <= 0.0 {
+            return Err("Length exponent must be bigger than zero")
+        }
+        self.length_exponent = length_exponent;
+        Ok(())
+    }
+
+    pub fn length_exponent(&self) -> f32 {
+        self.length_exponent
+    }
+
+    /// Sets scaling of maximal throw with length: throw = throw_coef * length^throw_exponent, where
+    /// length is in axis units and throw is in depth units (samples)
+    pub fn set_throw_scaling(&mut self, throw_coef: f32, throw_exponent: f32) -> Result<(), &'static str> {
+        if throw_coef < 0.0 || throw_exponent < 0.0 {
+            return Err("Throw scaling params can't be negative")
+        }
+        self.throw_coef = throw_coef;
+        self.throw_exponent = throw_exponent;
+        Ok(())
+    }
+
+    pub fn throw_scaling(&self) -> (f32, f32) {
+        (self.throw_coef, self.throw_exponent)
+    }
+
+    /// Sets ratio of fault's height in depth units to its length in axis units
+    pub fn set_height_ratio(&mut self, height_ratio: f32) -> Result<(), &'static str> {
+        if height_ratio <= 0.0 {
+            return Err("Height ratio must be bigger than zero")
+        }
+        self.height_ratio = height_ratio;
+        Ok(())
+    }
+
+    pub fn height_ratio(&self) -> f32 {
+        self.height_ratio
+    }
+
+    /// Sets part of reverse faults, other faults are normal
+    pub fn set_reverse_fraction(&mut self, reverse_fraction: f32) -> Result<(), &'static str> {
+        if !(0.0..=1.0).contains(&reverse_fraction) {
+            return Err("Reverse fraction must be between 0.0 and 1.0")
+        }
+        self.reverse_fraction = reverse_fraction;
+        Ok(())
+    }
+
+    pub fn reverse_fraction(&self) -> f32 {
+        self.reverse_fraction
+    }
+
+    /// Creates planar faults with centres inside area of axes and depth between 0 and max_depth
+    pub fn generate_faults(&self, x_ax: &Axis, y_ax: &Axis, max_depth: i32) -> Result<Vec<Fault3D>, &'static str> {
+        let (x_start, x_end) = (x_ax.start().min(x_ax.end()), x_ax.start().max(x_ax.end()));
+        let (y_start, y_end) = (y_ax.start().min(y_ax.end()), y_ax.start().max(y_ax.end()));
+
+        let faults_count = match self.faults_count {
+            Some(faults_count) => faults_count,
+            None => (self.density * (x_end - x_start) * (y_end - y_start)).round() as u32,
+        };
+
+        #[cfg(debug_assertions)]
+        trace!("Generating population of {} faults", faults_count);
+
+        let mut rng = rand::thread_rng();
+        let sets_dist = WeightedIndex::new(self.orientation_sets.iter().map(|set| set.weight))
+            .map_err(|_| "Weights of orientation sets are incorrect")?;
+
+        let (min_pow, max_pow) = (self.length_range[0].powf(-self.length_exponent), self.length_range[1].powf(-self.length_exponent));
+
+        let mut faults = Vec::with_capacity(faults_count as usize);
+        for _ in 0..faults_count {
+            let set = &self.orientation_sets[sets_dist.sample(&mut rng)];
+
+            // Inverse transform sampling of truncated power law
+            let length = (min_pow - rng.gen::<f32>() * (min_pow - max_pow)).powf(-1.0 / self.length_exponent);
+            let throw = (self.throw_coef * length.powf(self.throw_exponent)).round() as i32;
+
+            let mut fault = Fault3D::new();
+            fault.set_pos_x(rng.gen_range(x_start..=x_end));
+            fault.set_pos_y(rng.gen_range(y_start..=y_end));
+            fault.set_pos_z(rng.gen_range(0.0..=max_depth.max(0) as f32));
+            fault.set_strike(set.random_strike(&mut rng))?;
+            fault.set_dip(rng.gen_range(set.dip[0]..=set.dip[1]))?;
+            fault.set_throw(throw)?;
+            fault.set_fault_sense(if rng.gen::<f32>() < self.reverse_fraction { FaultSense::Reverse } else { FaultSense::Normal });
+            fault.set_displacement_profile(DisplacementProfile::Elliptical {
+                half_length: length / 2.0,
+                half_height: length / 2.0 * self.height_ratio,
+            })?;
+            faults.push(fault);
+        }
+
+        Ok(faults)
+    }
+}
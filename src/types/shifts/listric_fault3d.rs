@@ -0,0 +1,216 @@
+use crate::types::shifts::{ListricFault3D, TraceShape, FaultSense, DisplacementProfile};
+
+const SPLINE_SUBDIVISIONS: usize = 8;
+
+/// Signed distance from trace, distance along trace from its middle and dip direction (x, y)
+pub type TracePosition = (f32, f32, (f32, f32));
+
+impl Default for ListricFault3D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ListricFault3D {
+    pub fn new() -> ListricFault3D {
+        ListricFault3D {
+            trace: vec![[5.0, 0.0], [5.0, 10.0]],
+            trace_shape: TraceShape::Polyline,
+            trace_depth: 0.0,
+            surface_dip: 60.0,
+            detachment_depth: 100.0,
+            throw: 20,
+            fault_sense: FaultSense::Normal,
+            rollover: true,
+            displacement_profile: DisplacementProfile::Constant,
+        }
+    }
+}
+
+impl ListricFault3D {
+    pub fn set_trace(&mut self, trace: Vec<[f32; 2]>) -> Result<(), &'static str> {
+        if trace.len() < 2 {
+            return Err("Trace must contain at least two points")
+        }
+        for points in trace.windows(2) {
+            if points[0] == points[1] {
+                return Err("Neighbour points of trace must be different")
+            }
+        }
+        self.trace = trace;
+        Ok(())
+    }
+
+    pub fn trace(&self) -> &Vec<[f32; 2]> {
+        &self.trace
+    }
+
+    pub fn set_trace_shape(&mut self, trace_shape: TraceShape) {
+        self.trace_shape = trace_shape
+    }
+
+    pub fn trace_shape(&self) -> TraceShape {
+        self.trace_shape
+    }
+
+    /// Sets depth of the trace, fault has `surface_dip` at this depth, it must be above detachment
+    pub fn set_trace_depth(&mut self, trace_depth: f32) -> Result<(), &'static str> {
+        if trace_depth >= self.detachment_depth {
+            return Err("Trace must be above detachment")
+        }
+        self.trace_depth = trace_depth;
+        Ok(())
+    }
+
+    pub fn trace_depth(&self) -> f32 {
+        self.trace_depth
+    }
+
+    pub fn set_surface_dip(&mut self, surface_dip: f32) -> Result<(), &'static str> {
+        if surface_dip <= 0.0 || surface_dip >= 90.0 {
+            return Err("Surface dip should be between 0.0 and 90.0")
+        }
+        self.surface_dip = surface_dip;
+        Ok(())
+    }
+
+    pub fn surface_dip(&self) -> f32 {
+        self.surface_dip
+    }
+
+    /// Sets depth where fault becomes flat, it must be deeper than trace
+    pub fn set_detachment_depth(&mut self, detachment_depth: f32) -> Result<(), &'static str> {
+        if detachment_depth <= self.trace_depth {
+            return Err("Detachment must be deeper than trace")
+        }
+        self.detachment_depth = detachment_depth;
+        Ok(())
+    }
+
+    pub fn detachment_depth(&self) -> f32 {
+        self.detachment_depth
+    }
+
+    pub fn set_throw(&mut self, throw: i32) -> Result<(), &'static str> {
+        if throw < 0 {
+            return Err("Throw cannot be negative, use fault sense to set direction")
+        }
+        self.throw = throw;
+        Ok(())
+    }
+
+    pub fn throw(&self) -> i32 {
+        self.throw
+    }
+
+    pub fn set_fault_sense(&mut self, fault_sense: FaultSense) {
+        self.fault_sense = fault_sense
+    }
+
+    pub fn fault_sense(&self) -> FaultSense {
+        self.fault_sense
+    }
+
+    /// With rollover hanging wall collapses onto curved fault (vertical shear), so horizons bend
+    /// towards the fault. Without it hanging wall is moved by throw everywhere
+    pub fn set_rollover(&mut self, rollover: bool) {
+        self.rollover = rollover
+    }
+
+    pub fn rollover(&self) -> bool {
+        self.rollover
+    }
+
+    pub fn set_displacement_profile(&mut self, profile: DisplacementProfile) -> Result<(), &'static str> {
+        profile.validate()?;
+        self.displacement_profile = profile;
+        Ok(())
+    }
+
+    pub fn displacement_profile(&self) -> &DisplacementProfile {
+        &self.displacement_profile
+    }
+}
+
+impl ListricFault3D {
+    /// Returns points of trace, splines are subdivided into polyline
+    pub fn trace_points(&self) -> Vec<[f32; 2]> {
+        match self.trace_shape {
+            TraceShape::Polyline => self.trace.clone(),
+            TraceShape::Spline => {
+                let points = &self.trace;
+                let last = points.len() - 1;
+                let mut result: Vec<[f32; 2]> = Vec::with_capacity(last * SPLINE_SUBDIVISIONS + 1);
+
+                for i in 0..last {
+                    let p0 = points[i.saturating_sub(1)];
+                    let (p1, p2) = (points[i], points[i + 1]);
+                    let p3 = points[(i + 2).min(last)];
+
+                    for step in 0..SPLINE_SUBDIVISIONS {
+                        let t = step as f32 / SPLINE_SUBDIVISIONS as f32;
+                        result.push([
+                            catmull_rom(p0[0], p1[0], p2[0], p3[0], t),
+                            catmull_rom(p0[1], p1[1], p2[1], p3[1], t),
+                        ]);
+                    }
+                }
+                result.push(points[last]);
+                result
+            }
+        }
+    }
+
+    /// Depth of fault at horizontal distance from trace (positive towards hanging wall).
+    /// Dip decreases exponentially from `surface_dip` to zero at `detachment_depth`
+    pub fn fault_depth(&self, distance: f32) -> f32 {
+        let dip_tan = self.surface_dip.to_radians().tan();
+        if distance <= 0.0 {
+            return self.trace_depth + distance * dip_tan
+        }
+        let height = self.detachment_depth - self.trace_depth;
+
+        self.trace_depth + height * (1.0 - (-distance * dip_tan / height).exp())
+    }
+
+    /// Horizontal heave of hanging wall for vertical throw at the trace
+    pub fn heave(&self, throw: f32) -> f32 {
+        throw / self.surface_dip.to_radians().tan()
+    }
+
+    /// Finds position of point relative to trace: signed distance (positive towards hanging wall),
+    /// distance along trace from its middle and unit vector of dip direction
+    pub fn trace_position(trace: &[[f32; 2]], x: f32, y: f32) -> TracePosition {
+        let mut best = (f32::MAX, 0.0, 0.0, (0.0, 0.0));
+        let mut trace_len = 0.0;
+
+        for points in trace.windows(2) {
+            let ([ax, ay], [bx, by]) = (points[0], points[1]);
+            let (dx, dy) = (bx - ax, by - ay);
+            let len = (dx * dx + dy * dy).sqrt();
+            // Repeated points don't have direction
+            if len == 0.0 {
+                continue;
+            }
+            let (dir_x, dir_y) = (dx / len, dy / len);
+
+            let proj = ((x - ax) * dir_x + (y - ay) * dir_y).clamp(0.0, len);
+            let (near_x, near_y) = (ax + dir_x * proj, ay + dir_y * proj);
+            let dist = ((x - near_x).powi(2) + (y - near_y).powi(2)).sqrt();
+
+            if dist < best.0 {
+                // Right side of direction is hanging wall
+                let side = (x - ax) * dir_y - (y - ay) * dir_x;
+                best = (dist, if side >= 0.0 { dist } else { -dist }, trace_len + proj, (dir_y, -dir_x));
+            }
+            trace_len += len;
+        }
+
+        (best.1, best.2 - trace_len / 2.0, best.3)
+    }
+}
+
+fn catmull_rom(p0: f32, p1: f32, p2: f32, p3: f32, t: f32) -> f32 {
+    let (t2, t3) = (t * t, t * t * t);
+    0.5 * ((2.0 * p1) + (-p0 + p2) * t + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2 + (-p0 + 3.0 * p1 - 3.0 * p2 + p3) * t3)
+}
@@ -2,6 +2,13 @@ use serde::{Deserialize, Serialize};
 
 pub mod shift3d;
 pub mod shift2d;
+pub mod fault3d;
+pub mod displacement_profile;
+pub mod listric_fault3d;
+pub mod fault_population;
+pub mod rift_block3d;
+pub mod fault_drag;
+pub mod strike_slip_fault3d;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum ShiftTypes {
@@ -31,11 +38,172 @@ pub struct Shift3D {
     angle_z: f32,
     shift_force: i32,
     shift_type: ShiftTypes,
+    #[serde(default)]
+    displacement_profile: DisplacementProfile,
+    #[serde(default)]
+    drag: Option<FaultDrag>,
 }
 
+/// Shift of 2D section. Fault goes through pos_x at zero depth with dip angle, moving part is set by
+/// shift type. Horst and graben are bounded by two faults, width is measured between them at zero depth
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Shift2D {
     pos_x: f32,
     angle: f32,
     shift_force: i32,
     shift_type: ShiftTypes2D,
+    width: f32,
+}
+
+/// Normal drag bends horizons towards fault so offset looks smaller, reverse drag bends them away
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DragType {
+    Normal,
+    Reverse,
+}
+
+/// Smooth bending of borders near fault. Drag dies out quadratically from the fault to `width`
+/// (axis units), `intensity` is a part of shift force which is spread between both sides at the fault.
+/// Only shifts support drag, planar and listric faults, rift blocks and thrusts move borders rigidly
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FaultDrag {
+    drag_type: DragType,
+    width: f32,
+    intensity: f32,
+}
+
+/// Sense of movement of hanging wall: down the dip for normal faults, up the dip for reverse ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum FaultSense {
+    Normal,
+    Reverse,
+}
+
+/// Planar fault defined like geologists do: point on fault plane, strike azimuth (degrees clockwise
+/// from y axis), dip angle and vertical throw. Hanging wall lies to the right of strike (right-hand rule)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Fault3D {
+    pos_x: f32,
+    pos_y: f32,
+    pos_z: f32,
+    strike: f32,
+    dip: f32,
+    throw: i32,
+    fault_sense: FaultSense,
+    displacement_profile: DisplacementProfile,
+}
+
+/// Kind of block between two bounding faults: horst is lifted, graben is dropped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum RiftType {
+    Horst,
+    Graben,
+}
+
+/// Block bounded by two normal faults which is moved vertically by throw as a whole, bounding faults
+/// move together with it. Central axis goes through (pos_x, pos_y) at depth pos_z along strike,
+/// width is measured between faults at this depth.
+/// Left fault lies to the left of strike, right fault - to the right. Graben faults dip towards axis,
+/// horst faults dip away from it
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RiftBlock3D {
+    rift_type: RiftType,
+    pos_x: f32,
+    pos_y: f32,
+    pos_z: f32,
+    strike: f32,
+    width: f32,
+    left_dip: f32,
+    right_dip: f32,
+    throw: i32,
+    displacement_profile: DisplacementProfile,
+}
+
+/// Direction of lateral movement: block across the fault moves to the right of observer for
+/// dextral faults and to the left for sinistral ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum LateralSense {
+    Dextral,
+    Sinistral,
+}
+
+/// Vertical fault which moves block to the right of strike horizontally along the trace.
+/// Trace goes through (pos_x, pos_y), offset is measured in axis units
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StrikeSlipFault3D {
+    pos_x: f32,
+    pos_y: f32,
+    strike: f32,
+    offset: f32,
+    lateral_sense: LateralSense,
+    displacement_profile: DisplacementProfile,
+}
+
+/// How displacement changes from the centre of fault (maximal throw) to its tip line (zero throw).
+/// `half_length` is measured along strike in axis units, `half_height` down dip in depth units:
+/// along fault plane for planar faults, vertically for listric faults and rift blocks. Shifts
+/// have no centre depth, so they use only `half_length`
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub enum DisplacementProfile {
+    /// Same throw everywhere
+    #[default]
+    Constant,
+    /// Throw is multiplied by (1 - r^2), where r is normalized elliptical distance from the centre
+    Elliptical { half_length: f32, half_height: f32 },
+    /// Throw is multiplied by factor linearly interpolated from [r, factor] points, after the last
+    /// point factor goes linearly to zero at r = 1
+    Custom { half_length: f32, half_height: f32, profile: Vec<[f32; 2]> },
+}
+
+/// How fault trace is built from its points in map view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TraceShape {
+    Polyline,
+    /// Catmull-Rom spline going through all points
+    Spline,
+}
+
+/// Fault with curved trace in map view which dip flattens with depth (listric fault).
+/// Dip is `surface_dip` at the trace and goes to zero at `detachment_depth`.
+/// Hanging wall lies to the right of trace direction
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ListricFault3D {
+    trace: Vec<[f32; 2]>,
+    trace_shape: TraceShape,
+    trace_depth: f32,
+    surface_dip: f32,
+    detachment_depth: f32,
+    throw: i32,
+    fault_sense: FaultSense,
+    rollover: bool,
+    displacement_profile: DisplacementProfile,
+}
+
+/// Group of faults with similar orientation, strike and dip are picked uniformly from the ranges.
+/// Strike range can go through north, e.g. [350.0, 10.0]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OrientationSet {
+    strike: [f32; 2],
+    dip: [f32; 2],
+    weight: f32,
+}
+
+/// Stochastic fault network which is turned into planar faults inside model's extent.
+/// Fault lengths follow truncated power law (N(>L) ~ L^-length_exponent), throws are scaled
+/// as throw_coef * L^throw_exponent and taper elliptically to fault's tips.
+/// Lengths are measured in axis units, throws and heights in depth units (samples).
+/// Population is replaced by generated faults inside params of generated model
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FaultPopulation {
+    faults_count: Option<u32>,
+    // Number of faults per one square unit of x and y axes, used if faults count is not set
+    density: f32,
+    orientation_sets: Vec<OrientationSet>,
+    length_range: [f32; 2],
+    length_exponent: f32,
+    throw_coef: f32,
+    throw_exponent: f32,
+    // Ratio of fault's height (in depth units) to its length
+    height_ratio: f32,
+    reverse_fraction: f32,
 }
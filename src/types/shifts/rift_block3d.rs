@@ -0,0 +1,166 @@
+use crate::types::shifts::{RiftBlock3D, RiftType, Fault3D, DisplacementProfile};
+
+impl Default for RiftBlock3D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RiftBlock3D {
+    pub fn new() -> RiftBlock3D {
+        RiftBlock3D {
+            rift_type: RiftType::Graben,
+            pos_x: 5.0,
+            pos_y: 5.0,
+            pos_z: 0.0,
+            strike: 0.0,
+            width: 4.0,
+            left_dip: 60.0,
+            right_dip: 60.0,
+            throw: 20,
+            displacement_profile: DisplacementProfile::Constant,
+        }
+    }
+}
+
+impl RiftBlock3D {
+    pub fn set_rift_type(&mut self, rift_type: RiftType) {
+        self.rift_type = rift_type
+    }
+
+    pub fn rift_type(&self) -> RiftType {
+        self.rift_type
+    }
+
+    pub fn set_pos_x(&mut self, pos_x: f32) {
+        self.pos_x = pos_x
+    }
+
+    pub fn pos_x(&self) -> f32 {
+        self.pos_x
+    }
+
+    pub fn set_pos_y(&mut self, pos_y: f32) {
+        self.pos_y = pos_y
+    }
+
+    pub fn pos_y(&self) -> f32 {
+        self.pos_y
+    }
+
+    /// Sets depth where width of block is measured
+    pub fn set_pos_z(&mut self, pos_z: f32) {
+        self.pos_z = pos_z
+    }
+
+    pub fn pos_z(&self) -> f32 {
+        self.pos_z
+    }
+
+    pub fn set_strike(&mut self, strike: f32) -> Result<(), &'static str> {
+        if !(0.0..360.0).contains(&strike) {
+            return Err("Strike should be between 0.0 and 360.0")
+        }
+        self.strike = strike;
+        Ok(())
+    }
+
+    pub fn strike(&self) -> f32 {
+        self.strike
+    }
+
+    pub fn set_width(&mut self, width: f32) -> Result<(), &'static str> {
+        if width <= 0.0 {
+            return Err("Width of block must be bigger than zero")
+        }
+        self.width = width;
+        Ok(())
+    }
+
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Sets dips of left and right bounding faults, different dips make asymmetric block
+    pub fn set_dips(&mut self, left_dip: f32, right_dip: f32) -> Result<(), &'static str> {
+        for dip in [left_dip, right_dip] {
+            if dip <= 0.0 || dip > 90.0 {
+                return Err("Dip should be bigger than 0.0 and not bigger than 90.0")
+            }
+        }
+        self.left_dip = left_dip;
+        self.right_dip = right_dip;
+        Ok(())
+    }
+
+    pub fn dips(&self) -> (f32, f32) {
+        (self.left_dip, self.right_dip)
+    }
+
+    pub fn set_throw(&mut self, throw: i32) -> Result<(), &'static str> {
+        if throw < 0 {
+            return Err("Throw cannot be negative, use rift type to set direction")
+        }
+        self.throw = throw;
+        Ok(())
+    }
+
+    pub fn throw(&self) -> i32 {
+        self.throw
+    }
+
+    /// Sets how throw dies out along the axis, centre of profile is (pos_x, pos_y, pos_z)
+    pub fn set_displacement_profile(&mut self, profile: DisplacementProfile) -> Result<(), &'static str> {
+        profile.validate()?;
+        self.displacement_profile = profile;
+        Ok(())
+    }
+
+    pub fn displacement_profile(&self) -> &DisplacementProfile {
+        &self.displacement_profile
+    }
+}
+
+impl RiftBlock3D {
+    /// Returns left and right bounding faults, throw and displacement profile are the same as block's ones
+    pub fn bounding_faults(&self) -> Result<[Fault3D; 2], &'static str> {
+        let right_azimuth = (self.strike + 90.0).to_radians();
+        let (right_x, right_y) = (right_azimuth.sin() * self.width / 2.0, right_azimuth.cos() * self.width / 2.0);
+        let opposite_strike = (self.strike + 180.0) % 360.0;
+
+        // Hanging wall of Fault3D is to the right of its strike
+        let (left_strike, right_strike) = match self.rift_type {
+            RiftType::Graben => (self.strike, opposite_strike),
+            RiftType::Horst => (opposite_strike, self.strike),
+        };
+
+        let mut faults = [Fault3D::new(), Fault3D::new()];
+        for (fault, (sign, strike, dip)) in faults.iter_mut()
+            .zip([(-1.0, left_strike, self.left_dip), (1.0, right_strike, self.right_dip)]) {
+            fault.set_pos_x(self.pos_x + sign * right_x);
+            fault.set_pos_y(self.pos_y + sign * right_y);
+            fault.set_pos_z(self.pos_z);
+            fault.set_strike(strike)?;
+            fault.set_dip(dip)?;
+            fault.set_throw(self.throw)?;
+            fault.set_displacement_profile(self.displacement_profile.clone())?;
+        }
+        Ok(faults)
+    }
+
+    /// Checks if point lies inside moving block, faults are the block's bounding faults
+    pub fn contains(&self, faults: &[Fault3D; 2], x: f32, y: f32, z: f32) -> bool {
+        match self.rift_type {
+            RiftType::Graben => faults.iter().all(|fault| fault.signed_distance(x, y, z) > 0.0),
+            RiftType::Horst => faults.iter().all(|fault| fault.signed_distance(x, y, z) <= 0.0),
+        }
+    }
+
+    /// Multiplier of throw for point, it depends on distance along axis and depth
+    pub fn throw_factor(&self, x: f32, y: f32, z: f32) -> f32 {
+        let strike = self.strike.to_radians();
+        let along_strike = (x - self.pos_x) * strike.sin() + (y - self.pos_y) * strike.cos();
+
+        self.displacement_profile.factor(along_strike, z - self.pos_z)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::types::shifts::{Shift2D, ShiftTypes2D};
+use crate::types::shifts::{Shift2D, ShiftTypes2D, Fault3D, FaultSense, RiftBlock3D, RiftType};
 
 impl Default for Shift2D {
     fn default() -> Self {
@@ -13,6 +13,7 @@ impl Shift2D {
             angle: 90.0,
             shift_force: 20,
             shift_type: ShiftTypes2D::RightDescent,
+            width: 4.0,
         }
     }
 }
@@ -53,4 +54,58 @@ impl Shift2D {
     pub fn shift_type(&self) -> ShiftTypes2D {
         self.shift_type.clone()
     }
+
+    /// Sets width of horst or graben, it is ignored by other shift types
+    pub fn set_width(&mut self, width: f32) -> Result<(), &'static str> {
+        if width <= 0.0 {
+            return Err("Width must be bigger than zero")
+        }
+        self.width = width;
+        Ok(())
+    }
+
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+}
+
+impl Shift2D {
+    /// Returns fault for one-sided shift types, section lies along x axis of the fault.
+    /// Faults dip towards moving part, lifts are reverse faults
+    pub fn to_fault(&self) -> Result<Option<Fault3D>, &'static str> {
+        let (strike, fault_sense) = match self.shift_type {
+            ShiftTypes2D::RightDescent => (0.0, FaultSense::Normal),
+            ShiftTypes2D::LeftDescent => (180.0, FaultSense::Normal),
+            ShiftTypes2D::RightLift => (0.0, FaultSense::Reverse),
+            ShiftTypes2D::LeftLift => (180.0, FaultSense::Reverse),
+            ShiftTypes2D::Horst | ShiftTypes2D::Graber => return Ok(None),
+        };
+
+        let mut fault = Fault3D::new();
+        fault.set_pos_x(self.pos_x);
+        fault.set_pos_y(0.0);
+        fault.set_strike(strike)?;
+        fault.set_dip(self.angle)?;
+        fault.set_throw(self.shift_force)?;
+        fault.set_fault_sense(fault_sense);
+        Ok(Some(fault))
+    }
+
+    /// Returns block for horst and graben shift types
+    pub fn to_rift_block(&self) -> Result<Option<RiftBlock3D>, &'static str> {
+        let rift_type = match self.shift_type {
+            ShiftTypes2D::Horst => RiftType::Horst,
+            ShiftTypes2D::Graber => RiftType::Graben,
+            _ => return Ok(None),
+        };
+
+        let mut block = RiftBlock3D::new();
+        block.set_rift_type(rift_type);
+        block.set_pos_x(self.pos_x);
+        block.set_pos_y(0.0);
+        block.set_width(self.width)?;
+        block.set_dips(self.angle, self.angle)?;
+        block.set_throw(self.shift_force)?;
+        Ok(Some(block))
+    }
 }
@@ -1,4 +1,4 @@
-use crate::types::shifts::{Shift3D, ShiftTypes};
+use crate::types::shifts::{Shift3D, ShiftTypes, DisplacementProfile, FaultDrag};
 
 impl Default for Shift3D {
     fn default() -> Self {
@@ -17,6 +17,8 @@ impl Shift3D {
             angle_z: 90.0,
             shift_force: 20,
             shift_type: ShiftTypes::InnerDescent,
+            displacement_profile: DisplacementProfile::Constant,
+            drag: None,
         }
     }
 }
@@ -105,4 +107,26 @@ impl Shift3D {
     pub fn shift_type(&self) -> ShiftTypes {
         self.shift_type.clone()
     }
+
+    /// Sets how shift force dies out with distance from crossing point of shift's lines,
+    /// `half_height` of profile is ignored
+    pub fn set_displacement_profile(&mut self, profile: DisplacementProfile) -> Result<(), &'static str> {
+        profile.validate()?;
+        self.displacement_profile = profile;
+        Ok(())
+    }
+
+    pub fn displacement_profile(&self) -> &DisplacementProfile {
+        &self.displacement_profile
+    }
+
+    /// Sets bending of borders near shift's lines, None means rigid shift. Only borders above slice
+    /// depth (below it for lifts) are bent, on both sides of shift's lines
+    pub fn set_drag(&mut self, drag: Option<FaultDrag>) {
+        self.drag = drag
+    }
+
+    pub fn drag(&self) -> &Option<FaultDrag> {
+        &self.drag
+    }
 }
@@ -0,0 +1,104 @@
+use crate::types::shifts::{StrikeSlipFault3D, LateralSense, DisplacementProfile};
+
+impl Default for StrikeSlipFault3D {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StrikeSlipFault3D {
+    pub fn new() -> StrikeSlipFault3D {
+        StrikeSlipFault3D {
+            pos_x: 5.0,
+            pos_y: 5.0,
+            strike: 0.0,
+            offset: 2.0,
+            lateral_sense: LateralSense::Dextral,
+            displacement_profile: DisplacementProfile::Constant,
+        }
+    }
+}
+
+impl StrikeSlipFault3D {
+    pub fn set_pos_x(&mut self, pos_x: f32) {
+        self.pos_x = pos_x
+    }
+
+    pub fn pos_x(&self) -> f32 {
+        self.pos_x
+    }
+
+    pub fn set_pos_y(&mut self, pos_y: f32) {
+        self.pos_y = pos_y
+    }
+
+    pub fn pos_y(&self) -> f32 {
+        self.pos_y
+    }
+
+    pub fn set_strike(&mut self, strike: f32) -> Result<(), &'static str> {
+        if !(0.0..360.0).contains(&strike) {
+            return Err("Strike should be between 0.0 and 360.0")
+        }
+        self.strike = strike;
+        Ok(())
+    }
+
+    pub fn strike(&self) -> f32 {
+        self.strike
+    }
+
+    pub fn set_offset(&mut self, offset: f32) -> Result<(), &'static str> {
+        if offset < 0.0 {
+            return Err("Offset cannot be negative, use lateral sense to set direction")
+        }
+        self.offset = offset;
+        Ok(())
+    }
+
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    pub fn set_lateral_sense(&mut self, lateral_sense: LateralSense) {
+        self.lateral_sense = lateral_sense
+    }
+
+    pub fn lateral_sense(&self) -> LateralSense {
+        self.lateral_sense
+    }
+
+    /// Sets how offset dies out along the trace and with depth, centre of profile is (pos_x, pos_y)
+    /// at zero depth
+    pub fn set_displacement_profile(&mut self, profile: DisplacementProfile) -> Result<(), &'static str> {
+        profile.validate()?;
+        self.displacement_profile = profile;
+        Ok(())
+    }
+
+    pub fn displacement_profile(&self) -> &DisplacementProfile {
+        &self.displacement_profile
+    }
+}
+
+impl StrikeSlipFault3D {
+    /// Signed horizontal distance from trace, positive values are inside moving block
+    pub fn signed_distance(&self, x: f32, y: f32) -> f32 {
+        let right_azimuth = (self.strike + 90.0).to_radians();
+        (x - self.pos_x) * right_azimuth.sin() + (y - self.pos_y) * right_azimuth.cos()
+    }
+
+    /// Horizontal displacement (x, y) of moving block at the point, offset is scaled by displacement profile
+    pub fn displacement(&self, x: f32, y: f32, z: f32) -> (f32, f32) {
+        let strike = self.strike.to_radians();
+        let (strike_x, strike_y) = (strike.sin(), strike.cos());
+        let along_strike = (x - self.pos_x) * strike_x + (y - self.pos_y) * strike_y;
+        let offset = self.offset * self.displacement_profile.factor(along_strike, z);
+
+        // Block to the right of strike moves backwards along strike for dextral faults
+        match self.lateral_sense {
+            LateralSense::Dextral => (-offset * strike_x, -offset * strike_y),
+            LateralSense::Sinistral => (offset * strike_x, offset * strike_y),
+        }
+    }
+}